@@ -54,6 +54,11 @@ macro_rules! check_and_return {
             return Some($path);
         }
     };
+    // Also record the searched path, e.g., to report it if the library cannot be found.
+    ($path: expr, $searched: expr) => {
+        check_and_return!($path);
+        $searched.push($path);
+    };
 }
 
 /// Find the path to an OpenVINO library.
@@ -88,6 +93,28 @@ macro_rules! check_and_return {
 ///
 /// Panics if it cannot list the contents of a search directory.
 pub fn find(library_name: &str) -> Option<PathBuf> {
+    search(library_name, &mut Vec::new())
+}
+
+/// Find the path to an OpenVINO library like [`find`] but, if it cannot be found, return every
+/// path that was searched for it; this helps explain a missing library (e.g., an unset
+/// `LD_LIBRARY_PATH`).
+///
+/// # Errors
+///
+/// Returns the searched paths, in search order, if the library cannot be found.
+///
+/// # Panics
+///
+/// Panics if it cannot list the contents of a search directory.
+pub fn try_find(library_name: &str) -> Result<PathBuf, Vec<PathBuf>> {
+    let mut searched = Vec::new();
+    search(library_name, &mut searched).ok_or(searched)
+}
+
+/// Search for the library in the locations described in [`find`], recording each path checked in
+/// `searched`.
+fn search(library_name: &str, searched: &mut Vec<PathBuf>) -> Option<PathBuf> {
     let file = format!(
         "{}{}{}",
         env::consts::DLL_PREFIX,
@@ -102,7 +129,7 @@ pub fn find(library_name: &str) -> Option<PathBuf> {
         let install_dir = PathBuf::from(build_dir);
         for lib_dir in KNOWN_BUILD_SUBDIRECTORIES {
             let search_path = install_dir.join(lib_dir).join(&file);
-            check_and_return!(search_path);
+            check_and_return!(search_path, searched);
         }
     }
 
@@ -112,7 +139,7 @@ pub fn find(library_name: &str) -> Option<PathBuf> {
         let install_dir = PathBuf::from(install_dir);
         for lib_dir in KNOWN_INSTALLATION_SUBDIRECTORIES {
             let search_path = install_dir.join(lib_dir).join(&file);
-            check_and_return!(search_path);
+            check_and_return!(search_path, searched);
         }
     }
 
@@ -122,7 +149,7 @@ pub fn find(library_name: &str) -> Option<PathBuf> {
         let install_dir = PathBuf::from(install_dir);
         for lib_dir in KNOWN_INSTALLATION_SUBDIRECTORIES {
             let search_path = install_dir.join(lib_dir).join(&file);
-            check_and_return!(search_path);
+            check_and_return!(search_path, searched);
         }
     }
 
@@ -131,7 +158,7 @@ pub fn find(library_name: &str) -> Option<PathBuf> {
     if let Some(path) = env::var_os(ENV_LIBRARY_PATH) {
         for lib_dir in env::split_paths(&path) {
             let search_path = lib_dir.join(&file);
-            check_and_return!(search_path);
+            check_and_return!(search_path, searched);
         }
     }
 
@@ -144,13 +171,13 @@ pub fn find(library_name: &str) -> Option<PathBuf> {
     {
         // Check if the file is located in the installation directory.
         let search_path = install_dir.join(&file);
-        check_and_return!(search_path);
+        check_and_return!(search_path, searched);
 
         // Otherwise, check for version terminators: e.g., `libfoo.so.3.1.2`.
         let filenames = list_directory(&install_dir).expect("cannot list installation directory");
         let versions = get_suffixes(filenames, &file);
         if let Some(path) = build_latest_version(&install_dir, &file, versions) {
-            check_and_return!(path);
+            check_and_return!(path, searched);
         }
     }
 
@@ -162,7 +189,7 @@ pub fn find(library_name: &str) -> Option<PathBuf> {
     {
         for lib_dir in KNOWN_INSTALLATION_SUBDIRECTORIES {
             let search_path = default_dir.join(lib_dir).join(&file);
-            check_and_return!(search_path);
+            check_and_return!(search_path, searched);
         }
     }
 
@@ -316,6 +343,19 @@ mod test {
         assert!(find("openvino_c").is_some());
     }
 
+    /// This test shows that a library which cannot be found is reported with the paths searched
+    /// for it.
+    #[test]
+    fn report_searched_paths() {
+        let file = format!(
+            "{}missing_library{}",
+            env::consts::DLL_PREFIX,
+            env::consts::DLL_SUFFIX
+        );
+        let searched = try_find("missing_library").unwrap_err();
+        assert!(searched.iter().all(|path| path.ends_with(&file)));
+    }
+
     /// This test shows how the finder would discover the latest shared library on an
     /// APT installation.
     #[test]
@@ -0,0 +1,174 @@
+//! Add convenience methods to the generated OpenVINO enums.
+
+use crate::{layout_e, precision_e};
+use std::fmt;
+use std::str::FromStr;
+
+impl precision_e {
+    /// Return the width, in bytes, of a single element of this precision. Sub-byte precisions
+    /// (`U4`, `I4`, `BIN`) report `1`, the smallest addressable unit, and precisions without a
+    /// fixed element type (`UNSPECIFIED`, `MIXED`, `CUSTOM`) report `0`.
+    pub fn size_in_bytes(&self) -> usize {
+        match self {
+            Self::UNSPECIFIED | Self::MIXED | Self::CUSTOM => 0,
+            Self::U4 | Self::I4 | Self::BIN | Self::U8 | Self::I8 => 1,
+            Self::FP16 | Self::Q78 | Self::I16 | Self::U16 => 2,
+            Self::FP32 | Self::I32 | Self::U32 => 4,
+            Self::FP64 | Self::I64 | Self::U64 => 8,
+        }
+    }
+}
+
+impl layout_e {
+    /// Return the number of dimensions a tensor with this layout has, e.g., `4` for `NCHW` and `0`
+    /// for `SCALAR`. Layouts which do not fix the number of dimensions (`ANY`, `BLOCKED`) return
+    /// `None`.
+    pub fn rank(&self) -> Option<usize> {
+        match self {
+            Self::ANY | Self::BLOCKED => None,
+            Self::SCALAR => Some(0),
+            Self::C => Some(1),
+            Self::HW | Self::NC | Self::CN => Some(2),
+            Self::CHW => Some(3),
+            Self::NCHW | Self::NHWC | Self::OIHW => Some(4),
+            Self::NCDHW | Self::NDHWC => Some(5),
+        }
+    }
+}
+
+/// The error returned when parsing an unknown precision or layout name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseEnumError {
+    kind: &'static str,
+    value: String,
+}
+
+impl fmt::Display for ParseEnumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown OpenVINO {}: {:?}", self.kind, self.value)
+    }
+}
+
+impl std::error::Error for ParseEnumError {}
+
+/// Implement `Display` and (case-insensitive) `FromStr` for a generated enum using the canonical
+/// OpenVINO spelling of each variant, which is also its Rust name.
+macro_rules! string_conversions {
+    ($ty: ident, $kind: literal, [$($variant: ident),* $(,)?]) => {
+        impl fmt::Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                let name = match self {
+                    $(Self::$variant => stringify!($variant),)*
+                };
+                f.write_str(name)
+            }
+        }
+
+        impl FromStr for $ty {
+            type Err = ParseEnumError;
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $(if s.eq_ignore_ascii_case(stringify!($variant)) {
+                    return Ok(Self::$variant);
+                })*
+                Err(ParseEnumError {
+                    kind: $kind,
+                    value: s.to_string(),
+                })
+            }
+        }
+    };
+}
+
+string_conversions!(
+    precision_e,
+    "precision",
+    [
+        UNSPECIFIED,
+        MIXED,
+        FP32,
+        FP16,
+        FP64,
+        Q78,
+        I16,
+        U4,
+        U8,
+        I4,
+        I8,
+        U16,
+        I32,
+        I64,
+        U64,
+        U32,
+        BIN,
+        CUSTOM
+    ]
+);
+
+string_conversions!(
+    layout_e,
+    "layout",
+    [ANY, NCHW, NHWC, NCDHW, NDHWC, OIHW, SCALAR, C, CHW, HW, NC, CN, BLOCKED]
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        for precision in [precision_e::FP32, precision_e::FP16, precision_e::U8] {
+            assert_eq!(precision.to_string().parse(), Ok(precision));
+        }
+        for layout in [layout_e::NCHW, layout_e::NHWC, layout_e::C] {
+            assert_eq!(layout.to_string().parse(), Ok(layout));
+        }
+    }
+
+    #[test]
+    fn layout_ranks() {
+        assert_eq!(layout_e::NCHW.rank(), Some(4));
+        assert_eq!(layout_e::NDHWC.rank(), Some(5));
+        assert_eq!(layout_e::NC.rank(), Some(2));
+        assert_eq!(layout_e::SCALAR.rank(), Some(0));
+        assert_eq!(layout_e::ANY.rank(), None);
+        assert_eq!(layout_e::BLOCKED.rank(), None);
+    }
+
+    #[test]
+    fn precision_coverage() {
+        // Every variant of the C `precision_e`, its C value and its element width.
+        let precisions = [
+            (precision_e::UNSPECIFIED, 255, 0),
+            (precision_e::MIXED, 0, 0),
+            (precision_e::FP32, 10, 4),
+            (precision_e::FP16, 11, 2),
+            (precision_e::FP64, 13, 8),
+            (precision_e::Q78, 20, 2),
+            (precision_e::I16, 30, 2),
+            (precision_e::U4, 39, 1),
+            (precision_e::U8, 40, 1),
+            (precision_e::I4, 49, 1),
+            (precision_e::I8, 50, 1),
+            (precision_e::U16, 60, 2),
+            (precision_e::I32, 70, 4),
+            (precision_e::I64, 72, 8),
+            (precision_e::U64, 73, 8),
+            (precision_e::U32, 74, 4),
+            (precision_e::BIN, 71, 1),
+            (precision_e::CUSTOM, 80, 0),
+        ];
+        for (precision, value, size) in precisions {
+            assert_eq!(precision as u32, value);
+            assert_eq!(precision.size_in_bytes(), size, "{precision}");
+            assert_eq!(precision.to_string().parse(), Ok(precision));
+        }
+    }
+
+    #[test]
+    fn parse_case_insensitively() {
+        assert_eq!("fp16".parse(), Ok(precision_e::FP16));
+        assert_eq!("nhwc".parse(), Ok(layout_e::NHWC));
+        let error = "FP8".parse::<precision_e>().unwrap_err();
+        assert_eq!(error.to_string(), "unknown OpenVINO precision: \"FP8\"");
+    }
+}
@@ -29,6 +29,9 @@ mod linking;
 mod generated;
 pub use generated::*;
 
+mod enums;
+pub use enums::ParseEnumError;
+
 /// Contains extra utilities for finding and loading the OpenVINO shared libraries.
 pub mod library {
     use std::path::PathBuf;
@@ -0,0 +1,225 @@
+//! Measure the steady-state performance of an [`ExecutableNetwork`], similar to OpenVINO's
+//! `benchmark_app`.
+
+use crate::network::ExecutableNetwork;
+use crate::request::InferRequest;
+use crate::util::Result;
+use crate::{Blob, InferenceError, Precision, TensorDesc};
+use std::sync::mpsc;
+use std::time::{Duration, Instant};
+
+/// Summarize the latencies and throughput measured by [`ExecutableNetwork::benchmark`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BenchmarkResult {
+    /// The number of inferences measured.
+    pub iterations: usize,
+    /// The number of inference requests kept in flight.
+    pub concurrent_requests: usize,
+    /// The wall-clock time taken by all inferences.
+    pub total: Duration,
+    /// The mean latency of a single inference.
+    pub mean_latency: Duration,
+    /// The median latency of a single inference.
+    pub median_latency: Duration,
+    /// The 99th-percentile latency of a single inference.
+    pub p99_latency: Duration,
+    /// The throughput, in inferences per second.
+    pub fps: f64,
+}
+
+impl BenchmarkResult {
+    /// Compute the statistics from the `latencies` of each inference and the `total` time taken.
+    fn new(mut latencies: Vec<Duration>, concurrent_requests: usize, total: Duration) -> Self {
+        latencies.sort_unstable();
+        let iterations = latencies.len();
+        let percentile = |p: usize| latencies[(iterations * p / 100).min(iterations - 1)];
+        #[allow(clippy::cast_possible_truncation)]
+        let mean_latency = latencies.iter().sum::<Duration>() / iterations as u32;
+        #[allow(clippy::cast_precision_loss)]
+        let fps = iterations as f64 / total.as_secs_f64();
+        Self {
+            iterations,
+            concurrent_requests,
+            total,
+            mean_latency,
+            median_latency: percentile(50),
+            p99_latency: percentile(99),
+            fps,
+        }
+    }
+}
+
+impl ExecutableNetwork {
+    /// Run `iterations` inferences spread over `concurrent_requests` asynchronous
+    /// [`InferRequest`]s and report their latencies and overall throughput. Each request is
+    /// restarted as soon as it completes, so the device is kept busy as in a serving workload; a
+    /// good value for `concurrent_requests` is the `OPTIMAL_NUMBER_OF_INFER_REQUESTS` metric.
+    ///
+    /// Every input is filled with pseudo-random data of the precision and shape reported by
+    /// [`ExecutableNetwork::get_input_info`]: floating-point inputs receive values in `[0, 1)` and
+    /// other precisions random bytes. Imported networks (see
+    /// [`Core::import_network`](crate::Core::import_network)) do not know their input names, so
+    /// their requests infer on the input blobs allocated by the plugin, as-is.
+    ///
+    /// Returns [`InferenceError::OutOfBounds`] if `iterations` or `concurrent_requests` is `0`.
+    pub fn benchmark(
+        &self,
+        iterations: usize,
+        concurrent_requests: usize,
+    ) -> Result<BenchmarkResult> {
+        if iterations == 0 || concurrent_requests == 0 {
+            return Err(InferenceError::OutOfBounds);
+        }
+
+        let mut random = XorShift::default();
+        let inputs = self
+            .names
+            .inputs
+            .iter()
+            .map(|name| Ok((name, random.blob(&self.get_input_info(name)?)?)))
+            .collect::<Result<Vec<_>>>()?;
+
+        // Each request signals its completion (by index) on this channel.
+        let (sender, receiver) = mpsc::channel();
+        let mut requests = Vec::with_capacity(concurrent_requests);
+        for index in 0..concurrent_requests.min(iterations) {
+            let mut request = self.create_infer_request()?;
+            for (name, blob) in &inputs {
+                request.set_blob(name, blob)?;
+            }
+            let sender = sender.clone();
+            request.set_completion_callback(move || {
+                let _ = sender.send((index, Instant::now()));
+            })?;
+            requests.push(request);
+        }
+
+        let start = Instant::now();
+        let latencies = run(&mut requests, &receiver, iterations);
+        let total = start.elapsed();
+        if latencies.is_err() {
+            // Do not drop the requests (and their callbacks) while inferences are still running;
+            // waiting on a request that was never started fails immediately.
+            for request in &requests {
+                let _ = request.wait(InferRequest::WAIT_INFINITE);
+            }
+        }
+
+        Ok(BenchmarkResult::new(latencies?, requests.len(), total))
+    }
+}
+
+/// Keep the `requests` busy until `iterations` inferences have completed, as signalled on
+/// `completions`, and return their latencies. On error, some requests may still be running.
+fn run(
+    requests: &mut [InferRequest],
+    completions: &mpsc::Receiver<(usize, Instant)>,
+    iterations: usize,
+) -> Result<Vec<Duration>> {
+    let mut started = Vec::with_capacity(requests.len());
+    for request in requests.iter_mut() {
+        started.push(Instant::now());
+        request.infer_async()?;
+    }
+    let mut submitted = requests.len();
+
+    let mut latencies = Vec::with_capacity(iterations);
+    while latencies.len() < iterations {
+        // The requests own a sender, so the channel cannot be closed.
+        let (index, finished) = completions.recv().map_err(|_| InferenceError::Unexpected)?;
+        // The callback may run before the request is fully released; waiting here makes sure it
+        // can be restarted.
+        requests[index].wait(InferRequest::WAIT_INFINITE)?;
+        latencies.push(finished - started[index]);
+        if submitted < iterations {
+            started[index] = Instant::now();
+            requests[index].infer_async()?;
+            submitted += 1;
+        }
+    }
+    Ok(latencies)
+}
+
+/// Generate the pseudo-random benchmark inputs with a xorshift generator; the data only needs to
+/// be plausible, not statistically sound.
+struct XorShift(u64);
+
+impl Default for XorShift {
+    fn default() -> Self {
+        Self(0x2545_f491_4f6c_dd1d)
+    }
+}
+
+impl XorShift {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    /// Allocate a [`Blob`] for `description` and fill it with random elements of its precision.
+    fn blob(&mut self, description: &TensorDesc) -> Result<Blob> {
+        let mut blob = Blob::allocate(description)?;
+        let buffer = blob.buffer_mut()?;
+        match description.precision() {
+            Precision::FP32 => {
+                for element in buffer.chunks_exact_mut(4) {
+                    // 24 random mantissa bits, scaled into `[0, 1)`.
+                    #[allow(clippy::cast_precision_loss)]
+                    let value = (self.next() >> 40) as f32 / (1 << 24) as f32;
+                    element.copy_from_slice(&value.to_ne_bytes());
+                }
+            }
+            Precision::FP64 => {
+                for element in buffer.chunks_exact_mut(8) {
+                    #[allow(clippy::cast_precision_loss)]
+                    let value = (self.next() >> 11) as f64 / (1u64 << 53) as f64;
+                    element.copy_from_slice(&value.to_ne_bytes());
+                }
+            }
+            Precision::FP16 => {
+                for element in buffer.chunks_exact_mut(2) {
+                    // 10 random mantissa bits with an exponent of `-1`, i.e., `[0.5, 1)`.
+                    #[allow(clippy::cast_possible_truncation)]
+                    let bits = 0x3800 | (self.next() >> 54) as u16;
+                    element.copy_from_slice(&bits.to_ne_bytes());
+                }
+            }
+            _ => {
+                for chunk in buffer.chunks_mut(8) {
+                    let bytes = self.next().to_ne_bytes();
+                    chunk.copy_from_slice(&bytes[..chunk.len()]);
+                }
+            }
+        }
+        Ok(blob)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn statistics() {
+        let latencies = (1..=100).rev().map(Duration::from_millis).collect();
+        let result = BenchmarkResult::new(latencies, 2, Duration::from_secs(2));
+        assert_eq!(result.iterations, 100);
+        assert_eq!(result.concurrent_requests, 2);
+        assert_eq!(result.mean_latency, Duration::from_micros(50_500));
+        assert_eq!(result.median_latency, Duration::from_millis(51));
+        assert_eq!(result.p99_latency, Duration::from_millis(100));
+        assert!((result.fps - 50.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn random_inputs() {
+        openvino_sys::library::load().expect("unable to find an OpenVINO shared library");
+        let description = TensorDesc::new(crate::Layout::NC, &[2, 100], Precision::FP32);
+        let blob = XorShift::default().blob(&description).unwrap();
+        let values = blob.as_slice::<f32>().unwrap();
+        assert!(values.iter().all(|value| (0.0..1.0).contains(value)));
+        assert!(values.windows(2).any(|pair| pair[0] != pair[1]));
+    }
+}
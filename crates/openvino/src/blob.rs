@@ -1,41 +1,116 @@
 use crate::tensor_desc::TensorDesc;
 use crate::{drop_using_function, try_unsafe, util::Result, InferenceError};
+use crate::{BlobError, Precision, PrecisionType};
 use openvino_sys::{
     self, ie_blob_buffer__bindgen_ty_1, ie_blob_buffer_t, ie_blob_byte_size, ie_blob_free,
     ie_blob_get_buffer, ie_blob_get_dims, ie_blob_get_layout, ie_blob_get_precision,
-    ie_blob_make_memory, ie_blob_size, ie_blob_t, tensor_desc_t,
+    ie_blob_make_memory, ie_blob_make_memory_from_preallocated, ie_blob_make_memory_nv12,
+    ie_blob_make_memory_with_roi, ie_blob_size, ie_blob_t, roi_t, tensor_desc_t,
 };
 use std::convert::TryFrom;
+use std::io::{ErrorKind, Read};
 use std::mem::MaybeUninit;
+use std::sync::Arc;
 
 /// See [`Blob`](https://docs.openvinotoolkit.org/latest/classInferenceEngine_1_1Blob.html).
 pub struct Blob {
     pub(crate) instance: *mut ie_blob_t,
+    // The Rust-owned memory this blob refers to (see `Blob::from_vec`), if any; it is dropped after
+    // `instance` is freed.
+    pub(crate) memory: KeepAlive,
 }
 drop_using_function!(Blob, ie_blob_free);
 
+// The blob's memory is either reference-counted by OpenVINO or by `memory`, so a blob may be moved
+// to another thread.
+unsafe impl Send for Blob {}
+
+/// Own memory handed to OpenVINO by [`Blob::from_vec`]. OpenVINO does not know about this memory,
+/// so every Rust object which may hold on to OpenVINO data referring to it (e.g., an
+/// [`InferRequest`](crate::InferRequest) given such a blob) also holds a reference to it.
+pub(crate) struct OwnedMemory(*mut [u8]);
+
+impl Drop for OwnedMemory {
+    fn drop(&mut self) {
+        drop(unsafe { Box::from_raw(self.0) });
+    }
+}
+
+// The memory is only accessed through OpenVINO; this type just frees it.
+unsafe impl Send for OwnedMemory {}
+unsafe impl Sync for OwnedMemory {}
+
+/// The [`OwnedMemory`] an OpenVINO object refers to; empty for memory allocated by OpenVINO.
+pub(crate) type KeepAlive = Vec<Arc<OwnedMemory>>;
+
+/// Describe a rectangular region of interest within the spatial (height and width) dimensions of a
+/// [`Blob`]; see [`Blob::new_roi`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Roi {
+    /// The ID of the region.
+    pub id: usize,
+    /// The column of the upper-left corner of the region.
+    pub x: usize,
+    /// The row of the upper-left corner of the region.
+    pub y: usize,
+    /// The width of the region, in columns.
+    pub width: usize,
+    /// The height of the region, in rows.
+    pub height: usize,
+}
+
 impl Blob {
-    /// Create a new [`Blob`] by copying data in to the OpenVINO-allocated memory.
-    ///
-    /// # Panics
-    ///
-    /// This function will panic if the number of bytes passed in `data` does not match the expected
-    /// size of the tensor `description`.
-    pub fn new(description: &TensorDesc, data: &[u8]) -> Result<Self> {
+    /// Create a new [`Blob`] by copying data in to the OpenVINO-allocated memory. If the number of
+    /// bytes passed in `data` does not match the size of the tensor `description` (as computed by
+    /// OpenVINO), this returns [`BlobError::SizeMismatch`].
+    pub fn new(description: &TensorDesc, data: &[u8]) -> std::result::Result<Self, BlobError> {
         let mut blob = Self::allocate(description)?;
         let blob_len = blob.byte_len()?;
-        assert_eq!(
-            blob_len,
-            data.len(),
-            "The data to initialize ({} bytes) must be the same as the blob size ({} bytes).",
-            data.len(),
-            blob_len
-        );
+        if blob_len != data.len() {
+            return Err(BlobError::SizeMismatch {
+                expected: blob_len,
+                actual: data.len(),
+            });
+        }
 
-        // Copy the incoming data into the buffer.
-        let buffer = blob.buffer_mut()?;
-        buffer.copy_from_slice(data);
+        // Copy the incoming data into the buffer; an empty blob may not have a buffer to copy to.
+        if !data.is_empty() {
+            blob.buffer_mut()?.copy_from_slice(data);
+        }
+
+        Ok(blob)
+    }
 
+    /// Create a new [`Blob`] by reading its contents from `reader` directly into the
+    /// OpenVINO-allocated memory, avoiding an intermediate buffer. Exactly as many bytes as the
+    /// tensor `description` requires are read; anything after them is left in `reader`. If
+    /// `reader` ends early, this returns [`BlobError::SizeMismatch`] with the number of bytes
+    /// read, and any other I/O failure returns [`BlobError::Read`].
+    pub fn from_reader<R: Read>(
+        description: &TensorDesc,
+        reader: &mut R,
+    ) -> std::result::Result<Self, BlobError> {
+        let mut blob = Self::allocate(description)?;
+        let blob_len = blob.byte_len()?;
+        if blob_len == 0 {
+            return Ok(blob);
+        }
+
+        let buffer = blob.buffer_mut()?;
+        let mut read = 0;
+        while read < blob_len {
+            match reader.read(&mut buffer[read..]) {
+                Ok(0) => {
+                    return Err(BlobError::SizeMismatch {
+                        expected: blob_len,
+                        actual: read,
+                    })
+                }
+                Ok(n) => read += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => return Err(BlobError::Read(e.kind())),
+            }
+        }
         Ok(blob)
     }
 
@@ -46,7 +121,127 @@ impl Blob {
             std::ptr::addr_of!(description.instance),
             std::ptr::addr_of_mut!(instance)
         ))?;
-        Ok(Self { instance })
+        Ok(Self {
+            instance,
+            memory: KeepAlive::new(),
+        })
+    }
+
+    /// Create a new [`Blob`] that uses the memory of `data` directly rather than copying it into
+    /// OpenVINO-allocated memory. If the length of `data` does not match the size of the tensor
+    /// `description`, this returns [`BlobError::SizeMismatch`].
+    ///
+    /// `data` is freed once nothing refers to it anymore: the [`Blob`] itself, but also the blobs
+    /// derived from it (e.g., with [`Blob::new_roi`]), the requests it is assigned to (see
+    /// [`InferRequest::set_blob`](crate::InferRequest::set_blob)) and the networks reading their
+    /// weights from it (see [`Core::read_network_from_weights_blob`](crate::Core::read_network_from_weights_blob)).
+    pub fn from_vec(
+        description: &TensorDesc,
+        data: Vec<u8>,
+    ) -> std::result::Result<Self, BlobError> {
+        let expected = description.len() * description.precision().size_in_bytes();
+        if data.len() != expected {
+            return Err(BlobError::SizeMismatch {
+                expected,
+                actual: data.len(),
+            });
+        }
+
+        // Owning the memory before calling into OpenVINO frees it if the call fails.
+        let memory = OwnedMemory(Box::into_raw(data.into_boxed_slice()));
+        let mut instance = std::ptr::null_mut();
+        // OpenVINO expects the length of the memory in elements, not bytes.
+        try_unsafe!(ie_blob_make_memory_from_preallocated(
+            std::ptr::addr_of!(description.instance),
+            memory.0.cast(),
+            description.len(),
+            std::ptr::addr_of_mut!(instance)
+        ))?;
+        Ok(Self {
+            instance,
+            memory: vec![Arc::new(memory)],
+        })
+    }
+
+    /// Create a compound NV12 [`Blob`] from its `y` plane (with dimensions `[N, 1, H, W]`) and
+    /// interleaved `uv` plane (with dimensions `[N, 2, H / 2, W / 2]`); both planes must be `U8`
+    /// and `NHWC`. The compound [`Blob`] shares the memory of both planes. To use this as an input,
+    /// configure the input with [`ColorFormat::NV12`](crate::ColorFormat::NV12) (see
+    /// [`CNNNetwork::set_color_format`](crate::CNNNetwork::set_color_format)).
+    pub fn new_nv12(y: &Blob, uv: &Blob) -> std::result::Result<Self, BlobError> {
+        let y_dims = y.tensor_desc()?.dims().to_vec();
+        let uv_dims = uv.tensor_desc()?.dims().to_vec();
+        // Any other malformed planes are reported by OpenVINO itself.
+        if let &[n, _, h, w] = y_dims.as_slice() {
+            let expected = vec![n, 2, h / 2, w / 2];
+            if h % 2 != 0 || w % 2 != 0 || uv_dims != expected {
+                return Err(BlobError::ShapeMismatch {
+                    expected,
+                    actual: uv_dims,
+                });
+            }
+        }
+
+        let mut instance = std::ptr::null_mut();
+        try_unsafe!(ie_blob_make_memory_nv12(
+            y.instance,
+            uv.instance,
+            std::ptr::addr_of_mut!(instance)
+        ))?;
+        Ok(Self {
+            instance,
+            memory: y.memory.iter().chain(&uv.memory).cloned().collect(),
+        })
+    }
+
+    /// Create a [`Blob`] viewing the `roi` region of the `parent` blob's `[N, C, H, W]` data without
+    /// copying it. The new blob references the parent's memory: OpenVINO keeps that memory alive
+    /// for as long as either blob exists, and writes through one blob are visible in the other.
+    pub fn new_roi(parent: &Blob, roi: Roi) -> std::result::Result<Self, BlobError> {
+        let dims = parent.tensor_desc()?.dims().to_vec();
+        // Any other ranks are reported by OpenVINO itself.
+        if let &[_, _, height, width] = dims.as_slice() {
+            if roi.x + roi.width > width || roi.y + roi.height > height {
+                return Err(BlobError::RoiOutOfBounds { height, width });
+            }
+        }
+
+        let roi = roi_t {
+            id: roi.id,
+            posX: roi.x,
+            posY: roi.y,
+            sizeX: roi.width,
+            sizeY: roi.height,
+        };
+        let mut instance = std::ptr::null_mut();
+        try_unsafe!(ie_blob_make_memory_with_roi(
+            parent.instance,
+            std::ptr::addr_of!(roi),
+            std::ptr::addr_of_mut!(instance)
+        ))?;
+        Ok(Self {
+            instance,
+            memory: parent.memory.clone(),
+        })
+    }
+
+    /// Create a new [`Blob`] with the same tensor description and a copy of this [`Blob`]'s data.
+    /// This is an explicit deep copy--[`Blob`] does not implement [`Clone`] to avoid unexpected
+    /// allocations. Compound blobs (e.g., from [`Blob::new_nv12`]) have no contiguous buffer and
+    /// cannot be copied: this returns [`BlobError::Compound`].
+    pub fn deep_copy(&self) -> std::result::Result<Self, BlobError> {
+        let mut buffer = Blob::empty_buffer();
+        try_unsafe!(ie_blob_get_buffer(
+            self.instance,
+            std::ptr::addr_of_mut!(buffer)
+        ))?;
+        if unsafe { buffer.__bindgen_anon_1.buffer }.is_null() {
+            return Err(BlobError::Compound);
+        }
+
+        let mut copy = Self::allocate(&self.tensor_desc()?)?;
+        copy.buffer_mut()?.copy_from_slice(self.buffer()?);
+        Ok(copy)
     }
 
     /// Return the tensor description of this [`Blob`].
@@ -182,9 +377,137 @@ impl Blob {
         Ok(slice)
     }
 
-    /// Construct a Blob from its associated pointer.
-    pub(crate) unsafe fn from_raw_pointer(instance: *mut ie_blob_t) -> Self {
-        Self { instance }
+    /// Retrieve the [`Blob`]'s data as an immutable slice of `T`, without copying. Unlike
+    /// [`Blob::buffer_as_type`], this checks that the [`Blob`]'s precision matches `T` and that
+    /// its buffer is correctly sized and aligned for `T`.
+    pub fn as_slice<T: PrecisionType>(&self) -> std::result::Result<&[T], BlobError> {
+        let (data, len) = self.typed_buffer::<T>()?;
+        Ok(unsafe { std::slice::from_raw_parts(data, len) })
+    }
+
+    /// Retrieve the [`Blob`]'s data as a mutable slice of `T`, without copying. See
+    /// [`Blob::as_slice`].
+    pub fn as_mut_slice<T: PrecisionType>(&mut self) -> std::result::Result<&mut [T], BlobError> {
+        let (data, len) = self.typed_buffer::<T>()?;
+        Ok(unsafe { std::slice::from_raw_parts_mut(data, len) })
+    }
+
+    /// Set every element of the [`Blob`] to `value`; `T` must match the blob's precision (see
+    /// [`Blob::as_slice`]).
+    pub fn fill<T: PrecisionType>(&mut self, value: T) -> std::result::Result<(), BlobError> {
+        self.as_mut_slice::<T>()?.fill(value);
+        Ok(())
+    }
+
+    /// Set each element of the [`Blob`] to the result of calling `f` with that element's index
+    /// (in memory order); `T` must match the blob's precision (see [`Blob::as_slice`]).
+    pub fn fill_with<T: PrecisionType, F: FnMut(usize) -> T>(
+        &mut self,
+        mut f: F,
+    ) -> std::result::Result<(), BlobError> {
+        for (i, element) in self.as_mut_slice::<T>()?.iter_mut().enumerate() {
+            *element = f(i);
+        }
+        Ok(())
+    }
+
+    /// Retrieve the bytes of the sample at `index` along the [`Blob`]'s batch dimension (i.e., its
+    /// first dimension, `N` in `NCHW`), e.g., to post-process one result of a batched inference.
+    /// Returns [`BlobError::BatchOutOfBounds`] if `index` is not smaller than the batch size and
+    /// [`BlobError::NotBatched`] if the [`Blob`] has no dimensions.
+    pub fn batch_slice(&self, index: usize) -> std::result::Result<&[u8], BlobError> {
+        let range = self.batch_range(index, self.byte_len()?)?;
+        if range.is_empty() {
+            return Ok(&[]);
+        }
+        Ok(&self.buffer()?[range])
+    }
+
+    /// Retrieve the sample at `index` along the [`Blob`]'s batch dimension as a slice of `T`; `T`
+    /// must match the blob's precision (see [`Blob::as_slice`] and [`Blob::batch_slice`]).
+    pub fn batch_slice_as<T: PrecisionType>(
+        &self,
+        index: usize,
+    ) -> std::result::Result<&[T], BlobError> {
+        let data = self.as_slice::<T>()?;
+        Ok(&data[self.batch_range(index, data.len())?])
+    }
+
+    /// Compute the range of the sample at `index` within `len` units (e.g., bytes) of contents.
+    fn batch_range(
+        &self,
+        index: usize,
+        len: usize,
+    ) -> std::result::Result<std::ops::Range<usize>, BlobError> {
+        let desc = self.tensor_desc()?;
+        let batch = *desc.dims().first().ok_or(BlobError::NotBatched)?;
+        if index >= batch {
+            return Err(BlobError::BatchOutOfBounds { index, batch });
+        }
+        let sample = len / batch;
+        Ok(index * sample..(index + 1) * sample)
+    }
+
+    /// Read the elements of an `FP16` [`Blob`] (e.g., the output of a GPU network), widening each
+    /// to an `f32`. Returns [`BlobError::PrecisionMismatch`] for any other precision.
+    pub fn read_fp16_as_f32(&self) -> std::result::Result<Vec<f32>, BlobError> {
+        let precision = self.tensor_desc()?.precision();
+        if precision != Precision::FP16 {
+            return Err(BlobError::PrecisionMismatch {
+                expected: Precision::FP16,
+                actual: precision,
+            });
+        }
+        if self.byte_len()? == 0 {
+            return Ok(Vec::new());
+        }
+        Ok(self
+            .buffer()?
+            .chunks_exact(2)
+            .map(|bytes| f16_to_f32(u16::from_ne_bytes([bytes[0], bytes[1]])))
+            .collect())
+    }
+
+    /// Check that the [`Blob`]'s buffer can be viewed as a slice of `T`, returning the pointer to
+    /// and length of that slice.
+    fn typed_buffer<T: PrecisionType>(&self) -> std::result::Result<(*mut T, usize), BlobError> {
+        let precision = self.tensor_desc()?.precision();
+        if precision != T::PRECISION {
+            return Err(BlobError::PrecisionMismatch {
+                expected: T::PRECISION,
+                actual: precision,
+            });
+        }
+
+        let byte_len = self.byte_len()?;
+        let element_size = std::mem::size_of::<T>();
+        if byte_len % element_size != 0 {
+            return Err(BlobError::InvalidSize {
+                byte_len,
+                element_size,
+            });
+        }
+        if byte_len == 0 {
+            // An empty blob may not have a buffer at all, but slices must not be null.
+            return Ok((std::ptr::NonNull::dangling().as_ptr(), 0));
+        }
+
+        let mut buffer = Blob::empty_buffer();
+        try_unsafe!(ie_blob_get_buffer(
+            self.instance,
+            std::ptr::addr_of_mut!(buffer)
+        ))?;
+        let data = unsafe { buffer.__bindgen_anon_1.buffer }.cast::<T>();
+        let alignment = std::mem::align_of::<T>();
+        if data.align_offset(alignment) != 0 {
+            return Err(BlobError::Misaligned(alignment));
+        }
+        Ok((data, byte_len / element_size))
+    }
+
+    /// Construct a Blob from its associated pointer and the Rust-owned `memory` it refers to.
+    pub(crate) unsafe fn from_raw_pointer(instance: *mut ie_blob_t, memory: KeepAlive) -> Self {
+        Self { instance, memory }
     }
 
     fn empty_buffer() -> ie_blob_buffer_t {
@@ -196,17 +519,134 @@ impl Blob {
     }
 }
 
+/// Convert the bits of an IEEE 754 half-precision float to an `f32`; every value is exactly
+/// representable.
+fn f16_to_f32(bits: u16) -> f32 {
+    let sign = u32::from(bits >> 15) << 31;
+    let exponent = u32::from((bits >> 10) & 0x1f);
+    let mantissa = u32::from(bits & 0x3ff);
+    match exponent {
+        // Zeros and subnormals: `mantissa * 2^-24`, which is exact in `f32`.
+        0 => {
+            let magnitude = f32::from(bits & 0x3ff) * 2f32.powi(-24);
+            if sign == 0 {
+                magnitude
+            } else {
+                -magnitude
+            }
+        }
+        // Infinities and NaNs.
+        0x1f => f32::from_bits(sign | 0x7f80_0000 | (mantissa << 13)),
+        // Normal numbers: re-bias the exponent from 15 to 127.
+        _ => f32::from_bits(sign | ((exponent + 112) << 23) | (mantissa << 13)),
+    }
+}
+
+/// Convert between [`Blob`]s and [`ndarray`] arrays. The array shape is the order of the
+/// dimensions in memory, which differs from the [`TensorDesc`] dimensions (always in `NCHW` order)
+/// for the `NHWC` and `NDHWC` layouts; e.g., a `NHWC` descriptor with dimensions `[1, 3, 224, 224]`
+/// is viewed as an array of shape `[1, 224, 224, 3]`.
+#[cfg(feature = "ndarray")]
+impl Blob {
+    /// Create a new [`Blob`] by copying the elements of `array`, which must have the same
+    /// precision as `description` and the shape of `description` in memory.
+    pub fn from_ndarray<T, D>(
+        description: &TensorDesc,
+        array: &ndarray::ArrayView<T, D>,
+    ) -> std::result::Result<Self, BlobError>
+    where
+        T: PrecisionType,
+        D: ndarray::Dimension,
+    {
+        if description.precision() != T::PRECISION {
+            return Err(BlobError::PrecisionMismatch {
+                expected: description.precision(),
+                actual: T::PRECISION,
+            });
+        }
+        let expected = Self::memory_shape(description);
+        if array.shape() != expected.as_slice() {
+            return Err(BlobError::ShapeMismatch {
+                expected,
+                actual: array.shape().to_vec(),
+            });
+        }
+
+        let mut blob = Self::allocate(description)?;
+        // Iterating (rather than copying a slice) handles non-contiguous arrays.
+        for (to, from) in blob.as_mut_slice::<T>()?.iter_mut().zip(array.iter()) {
+            *to = *from;
+        }
+        Ok(blob)
+    }
+
+    /// View the [`Blob`]'s data as an array of `T`, without copying.
+    pub fn as_ndarray<T: PrecisionType>(
+        &self,
+    ) -> std::result::Result<ndarray::ArrayViewD<'_, T>, BlobError> {
+        let shape = Self::memory_shape(&self.tensor_desc()?);
+        let data = self.as_slice::<T>()?;
+        ndarray::ArrayViewD::from_shape(shape.clone(), data).map_err(|_| BlobError::ShapeMismatch {
+            expected: shape,
+            actual: vec![data.len()],
+        })
+    }
+
+    /// Copy the [`Blob`]'s data into an owned array of `T`; see [`Blob::as_ndarray`] to avoid the
+    /// copy.
+    pub fn to_ndarray<T: PrecisionType>(
+        &self,
+    ) -> std::result::Result<ndarray::ArrayD<T>, BlobError> {
+        Ok(self.as_ndarray::<T>()?.to_owned())
+    }
+
+    /// Order the dimensions of `description` as they are laid out in memory.
+    #[allow(clippy::many_single_char_names)]
+    fn memory_shape(description: &TensorDesc) -> Vec<usize> {
+        let dims = description.dims();
+        match (description.layout(), dims) {
+            (crate::Layout::NHWC, &[n, c, h, w]) => vec![n, h, w, c],
+            (crate::Layout::NDHWC, &[n, c, d, h, w]) => vec![n, d, h, w, c],
+            _ => dims.to_vec(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::{Layout, Precision};
 
     #[test]
-    #[should_panic]
     fn invalid_blob_size() {
+        openvino_sys::library::load().expect("unable to find an OpenVINO shared library");
+
+        // Blob should be 1x2x2x2 = 8 bytes but we pass in 7 or 9 bytes:
         let desc = TensorDesc::new(Layout::NHWC, &[1, 2, 2, 2], Precision::U8);
-        // Blob should be 1x2x2x2 = 8 bytes but we pass in 7 bytes:
-        let _ = Blob::new(&desc, &[0; 7]).unwrap();
+        assert_eq!(
+            Blob::new(&desc, &[0; 7]).err(),
+            Some(BlobError::SizeMismatch {
+                expected: 8,
+                actual: 7
+            })
+        );
+        assert_eq!(
+            Blob::new(&desc, &[0; 9]).err(),
+            Some(BlobError::SizeMismatch {
+                expected: 8,
+                actual: 9
+            })
+        );
+
+        // The size accounts for the element width: 4 FP32 elements are 16 bytes.
+        let desc = TensorDesc::new(Layout::NC, &[1, 4], Precision::FP32);
+        assert_eq!(
+            Blob::new(&desc, &[0; 4]).err(),
+            Some(BlobError::SizeMismatch {
+                expected: 16,
+                actual: 4
+            })
+        );
     }
 
     #[test]
@@ -238,6 +678,223 @@ mod tests {
         );
     }
 
+    #[test]
+    fn typed_slices() {
+        openvino_sys::library::load().expect("unable to find an OpenVINO shared library");
+
+        let desc = TensorDesc::new(Layout::NC, &[1, 4], Precision::FP32);
+        let mut blob = Blob::new(&desc, &[0; 16]).unwrap();
+        blob.as_mut_slice::<f32>()
+            .unwrap()
+            .copy_from_slice(&[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(blob.as_slice::<f32>().unwrap(), &[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(
+            blob.as_slice::<u8>(),
+            Err(BlobError::PrecisionMismatch {
+                expected: Precision::U8,
+                actual: Precision::FP32
+            })
+        );
+    }
+
+    #[test]
+    fn fill() {
+        openvino_sys::library::load().expect("unable to find an OpenVINO shared library");
+
+        let desc = TensorDesc::new(Layout::NC, &[2, 3], Precision::I32);
+        let mut blob = Blob::allocate(&desc).unwrap();
+        blob.fill(7i32).unwrap();
+        assert_eq!(blob.as_slice::<i32>().unwrap(), &[7; 6]);
+        blob.fill_with(|i| i as i32 * 2).unwrap();
+        assert_eq!(blob.as_slice::<i32>().unwrap(), &[0, 2, 4, 6, 8, 10]);
+        assert!(blob.fill(0.0f32).is_err());
+    }
+
+    #[cfg(feature = "ndarray")]
+    #[test]
+    fn ndarray_conversion() {
+        openvino_sys::library::load().expect("unable to find an OpenVINO shared library");
+
+        let desc = TensorDesc::new(Layout::NHWC, &[1, 3, 2, 2], Precision::FP32);
+        let array =
+            ndarray::Array::from_shape_fn((1, 2, 2, 3), |(_, h, w, c)| (h * 6 + w * 3 + c) as f32);
+        let blob = Blob::from_ndarray(&desc, &array.view()).unwrap();
+        assert_eq!(blob.as_slice::<f32>().unwrap()[..4], [0.0, 1.0, 2.0, 3.0]);
+        assert_eq!(blob.to_ndarray::<f32>().unwrap(), array.into_dyn());
+
+        let wrong_shape = ndarray::Array::<f32, _>::zeros((1, 3, 2, 2));
+        assert!(matches!(
+            Blob::from_ndarray(&desc, &wrong_shape.view()),
+            Err(BlobError::ShapeMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn half_precision_conversion() {
+        assert_eq!(f16_to_f32(0x0000).to_bits(), 0.0f32.to_bits());
+        assert_eq!(f16_to_f32(0x8000).to_bits(), (-0.0f32).to_bits());
+        assert_eq!(f16_to_f32(0x3c00), 1.0);
+        assert_eq!(f16_to_f32(0xc000), -2.0);
+        assert_eq!(f16_to_f32(0x7bff), 65504.0);
+        assert_eq!(f16_to_f32(0x0001), 2f32.powi(-24));
+        assert_eq!(f16_to_f32(0x7c00), f32::INFINITY);
+        assert!(f16_to_f32(0x7e00).is_nan());
+    }
+
+    #[test]
+    fn read_fp16() {
+        openvino_sys::library::load().expect("unable to find an OpenVINO shared library");
+
+        let desc = TensorDesc::new(Layout::NC, &[1, 2], Precision::FP16);
+        let data: Vec<u8> = [0x3c00u16, 0xc000]
+            .iter()
+            .flat_map(|bits| bits.to_ne_bytes())
+            .collect();
+        let blob = Blob::new(&desc, &data).unwrap();
+        assert_eq!(blob.read_fp16_as_f32().unwrap(), [1.0, -2.0]);
+
+        let desc = TensorDesc::new(Layout::NC, &[1, 2], Precision::FP32);
+        let blob = Blob::allocate(&desc).unwrap();
+        assert!(blob.read_fp16_as_f32().is_err());
+    }
+
+    #[test]
+    fn from_vec() {
+        openvino_sys::library::load().expect("unable to find an OpenVINO shared library");
+
+        let desc = TensorDesc::new(Layout::NC, &[1, 4], Precision::U8);
+        let data = vec![1, 2, 3, 4];
+        let data_ptr = data.as_ptr();
+        let blob = Blob::from_vec(&desc, data).unwrap();
+        assert_eq!(blob.buffer().unwrap().as_ptr(), data_ptr);
+        assert_eq!(blob.buffer().unwrap(), &[1, 2, 3, 4]);
+
+        // Derived blobs keep the memory alive.
+        let desc = TensorDesc::new(Layout::NCHW, &[1, 1, 2, 2], Precision::U8);
+        let parent = Blob::from_vec(&desc, vec![1, 2, 3, 4]).unwrap();
+        let roi = Roi {
+            id: 0,
+            x: 1,
+            y: 0,
+            width: 1,
+            height: 2,
+        };
+        let child = Blob::new_roi(&parent, roi).unwrap();
+        drop(parent);
+        assert_eq!(child.memory.len(), 1);
+        assert_eq!(child.tensor_desc().unwrap().dims(), &[1, 1, 2, 1]);
+
+        assert_eq!(
+            Blob::from_vec(&desc, vec![0; 3]).err(),
+            Some(BlobError::SizeMismatch {
+                expected: 4,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn from_reader() {
+        openvino_sys::library::load().expect("unable to find an OpenVINO shared library");
+
+        let desc = TensorDesc::new(Layout::NHWC, &[1, 2, 2, 2], Precision::U8);
+        let data: Vec<u8> = (0..10).collect();
+        let mut reader = &data[..];
+        let blob = Blob::from_reader(&desc, &mut reader).unwrap();
+        assert_eq!(blob.buffer().unwrap(), &data[..8]);
+        // The remaining bytes are left unread.
+        assert_eq!(reader, &[8, 9]);
+
+        // A truncated stream does not produce a blob.
+        assert_eq!(
+            Blob::from_reader(&desc, &mut &data[..5]).err(),
+            Some(BlobError::SizeMismatch {
+                expected: 8,
+                actual: 5
+            })
+        );
+    }
+
+    #[test]
+    fn batch_slices() {
+        openvino_sys::library::load().expect("unable to find an OpenVINO shared library");
+
+        let desc = TensorDesc::new(Layout::NC, &[3, 2], Precision::FP32);
+        let mut blob = Blob::allocate(&desc).unwrap();
+        blob.fill_with(|i| i as f32).unwrap();
+        assert_eq!(blob.batch_slice_as::<f32>(1), Ok(&[2.0, 3.0][..]));
+        assert_eq!(blob.batch_slice(2).unwrap().len(), 8);
+        assert_eq!(
+            blob.batch_slice(3),
+            Err(BlobError::BatchOutOfBounds { index: 3, batch: 3 })
+        );
+
+        let scalar = Blob::allocate(&TensorDesc::new(Layout::SCALAR, &[], Precision::U8)).unwrap();
+        assert_eq!(scalar.batch_slice(0), Err(BlobError::NotBatched));
+    }
+
+    #[test]
+    fn deep_copy() {
+        openvino_sys::library::load().expect("unable to find an OpenVINO shared library");
+
+        let desc = TensorDesc::new(Layout::NC, &[1, 4], Precision::U8);
+        let mut blob = Blob::new(&desc, &[1, 2, 3, 4]).unwrap();
+        let copy = blob.deep_copy().unwrap();
+        blob.buffer_mut().unwrap()[0] = 42;
+        assert_eq!(copy.buffer().unwrap(), &[1, 2, 3, 4]);
+        assert_eq!(copy.tensor_desc().unwrap().dims(), &[1, 4]);
+    }
+
+    #[test]
+    fn nv12_planes() {
+        openvino_sys::library::load().expect("unable to find an OpenVINO shared library");
+
+        let y_desc = TensorDesc::new(Layout::NHWC, &[1, 1, 4, 6], Precision::U8);
+        let y = Blob::new(&y_desc, &[0; 24]).unwrap();
+        let uv_desc = TensorDesc::new(Layout::NHWC, &[1, 2, 2, 3], Precision::U8);
+        let uv = Blob::new(&uv_desc, &[0; 12]).unwrap();
+        let nv12 = Blob::new_nv12(&y, &uv).unwrap();
+        assert_eq!(nv12.deep_copy().err(), Some(BlobError::Compound));
+
+        // The UV plane must be half the height of the Y plane.
+        let uv_desc = TensorDesc::new(Layout::NHWC, &[1, 2, 4, 3], Precision::U8);
+        let uv = Blob::new(&uv_desc, &[0; 24]).unwrap();
+        assert_eq!(
+            Blob::new_nv12(&y, &uv).err(),
+            Some(BlobError::ShapeMismatch {
+                expected: vec![1, 2, 2, 3],
+                actual: vec![1, 2, 4, 3]
+            })
+        );
+    }
+
+    #[test]
+    fn roi() {
+        openvino_sys::library::load().expect("unable to find an OpenVINO shared library");
+
+        let desc = TensorDesc::new(Layout::NCHW, &[1, 1, 4, 6], Precision::U8);
+        let data: Vec<u8> = (0..24).collect();
+        let parent = Blob::new(&desc, &data).unwrap();
+        let roi = Roi {
+            id: 0,
+            x: 2,
+            y: 1,
+            width: 3,
+            height: 2,
+        };
+        let view = Blob::new_roi(&parent, roi).unwrap();
+        assert_eq!(view.tensor_desc().unwrap().dims(), &[1, 1, 2, 3]);
+
+        let too_wide = Roi { width: 5, ..roi };
+        assert_eq!(
+            Blob::new_roi(&parent, too_wide).err(),
+            Some(BlobError::RoiOutOfBounds {
+                height: 4,
+                width: 6
+            })
+        );
+    }
+
     #[test]
     fn tensor_desc() {
         openvino_sys::library::load().expect("unable to find an OpenVINO shared library");
@@ -1,34 +1,67 @@
 //! Define the core interface between Rust and OpenVINO's C
 //! [API](https://docs.openvinotoolkit.org/latest/ie_c_api/modules.html).
 
-use crate::blob::Blob;
+use crate::blob::{Blob, KeepAlive};
+use crate::device::DeviceInfo;
+use crate::keys;
 use crate::tensor_desc::TensorDesc;
+use crate::util::{
+    empty_param, string_from_ptr, take_metric_param, take_string_param, Config, MetricKind,
+    TemporaryDir,
+};
 use crate::{cstr, drop_using_function, try_unsafe, util::Result};
 use crate::{
     error::{LoadingError, SetupError},
-    network::{CNNNetwork, ExecutableNetwork},
+    network::{max_dynamic_batch_size, CNNNetwork, ExecutableNetwork, IoNames},
 };
 use crate::{Layout, Precision};
 use openvino_sys::{
-    self, ie_config_t, ie_core_create, ie_core_free, ie_core_load_network, ie_core_read_network,
-    ie_core_read_network_from_memory, ie_core_t,
+    self, ie_available_devices_t, ie_core_add_extension, ie_core_available_devices_free,
+    ie_core_create, ie_core_free, ie_core_get_available_devices, ie_core_get_config,
+    ie_core_get_metric, ie_core_get_versions, ie_core_import_network,
+    ie_core_import_network_from_memory, ie_core_load_network, ie_core_load_network_from_file,
+    ie_core_read_network, ie_core_read_network_from_memory, ie_core_register_plugin,
+    ie_core_register_plugins, ie_core_set_config, ie_core_t, ie_core_version_t,
+    ie_core_versions_free, ie_core_versions_t,
 };
+use std::collections::HashMap;
+use std::ffi::CStr;
+use std::path::Path;
+use std::sync::Arc;
 
 /// See [Core](https://docs.openvinotoolkit.org/latest/classInferenceEngine_1_1Core.html).
+///
+/// Cloning a [`Core`] is cheap: all clones share the same OpenVINO core, which is freed once the
+/// last clone is dropped. OpenVINO's core is thread-safe, so every method only requires `&self`;
+/// note that methods changing the core's configuration or plugins (e.g., [`Core::set_config`])
+/// therefore affect all clones.
+#[derive(Clone)]
 pub struct Core {
+    shared: Arc<SharedCore>,
+}
+
+/// Own the OpenVINO core shared by all clones of a [`Core`].
+struct SharedCore {
     instance: *mut ie_core_t,
 }
-drop_using_function!(Core, ie_core_free);
+drop_using_function!(SharedCore, ie_core_free);
 
-unsafe impl Send for Core {}
+unsafe impl Send for SharedCore {}
+unsafe impl Sync for SharedCore {}
 
 impl Core {
     /// Construct a new OpenVINO [`Core`]--this is the primary entrypoint for constructing and using
     /// inference networks. Because this function may load OpenVINO's shared libraries at runtime,
     /// there are more ways than usual that this function can fail (e.g., [`LoadingError`]s).
+    ///
+    /// If `xml_config_file` is `None` and no `plugins.xml` can be found (see
+    /// [`openvino_finder::find_plugins_xml`]), OpenVINO falls back to its default plugin
+    /// configuration; if that provides no devices either, this returns
+    /// [`LoadingError::NoPluginsFound`] rather than a [`Core`] that cannot load any network.
     pub fn new(xml_config_file: Option<&str>) -> std::result::Result<Core, SetupError> {
-        openvino_sys::library::load().map_err(LoadingError::SystemFailure)?;
+        openvino_sys::library::load().map_err(LoadingError::system_failure)?;
 
+        let mut found_config = true;
         let file = if let Some(file) = xml_config_file {
             cstr!(file.to_string())
         } else if let Some(file) = openvino_finder::find_plugins_xml() {
@@ -37,73 +70,622 @@ impl Core {
                 .ok_or(LoadingError::CannotStringifyPath)?
                 .to_string())
         } else {
+            found_config = false;
             cstr!(String::new())
         };
 
         let mut instance = std::ptr::null_mut();
         try_unsafe!(ie_core_create(file, std::ptr::addr_of_mut!(instance)))?;
-        Ok(Core { instance })
+        let core = Core {
+            shared: Arc::new(SharedCore { instance }),
+        };
+        if !found_config && core.available_devices()?.is_empty() {
+            return Err(LoadingError::NoPluginsFound.into());
+        }
+        Ok(core)
+    }
+
+    /// Construct a new OpenVINO [`Core`] from the contents of a `plugins.xml` configuration rather
+    /// than a path to one (see [`Core::new`]). The C API only reads configurations from files, so
+    /// `xml` is written to a file in a private temporary directory that is removed once the
+    /// [`Core`] is created; this still requires a writable temporary directory (see
+    /// [`std::env::temp_dir`]). Because of this, plugin locations in `xml` should be absolute paths
+    /// or library names that the system loader can find, not paths relative to the configuration
+    /// file.
+    pub fn from_plugin_config(xml: &str) -> std::result::Result<Core, SetupError> {
+        // OpenVINO parses the configuration while creating the core, so the directory can be
+        // removed as soon as this returns.
+        let dir = TemporaryDir::new()?;
+        let path = dir.write("plugins.xml", xml.as_bytes())?;
+        let file = path.to_str().ok_or(LoadingError::CannotStringifyPath)?;
+        Core::new(Some(file))
     }
 
     /// Read a [`CNNNetwork`] from a pair of files: `model_path` points to an XML file containing the
     /// OpenVINO network IR and `weights_path` points to the binary weights file.
     pub fn read_network_from_file(
-        &mut self,
+        &self,
         model_path: &str,
         weights_path: &str,
     ) -> Result<CNNNetwork> {
         let mut instance = std::ptr::null_mut();
         try_unsafe!(ie_core_read_network(
-            self.instance,
+            self.shared.instance,
             cstr!(model_path),
             cstr!(weights_path),
             std::ptr::addr_of_mut!(instance)
         ))?;
-        Ok(CNNNetwork { instance })
+        Ok(CNNNetwork {
+            instance,
+            memory: KeepAlive::new(),
+        })
+    }
+
+    /// Read a [`CNNNetwork`] from `model_path` without specifying its weights: for an OpenVINO IR
+    /// model (e.g., `model.xml`), the weights are read from the file of the same name with a `.bin`
+    /// extension next to it (e.g., `model.bin`); an ONNX model (`.onnx`) embeds its weights and is
+    /// read with [`Core::read_onnx_from_file`]. If the weights file does not exist, this returns
+    /// [`SetupError::MissingWeights`] with its expected path.
+    pub fn read_network_auto_weights(
+        &self,
+        model_path: &str,
+    ) -> std::result::Result<CNNNetwork, SetupError> {
+        let model = Path::new(model_path);
+        if model
+            .extension()
+            .is_some_and(|extension| extension.eq_ignore_ascii_case("onnx"))
+        {
+            return self.read_onnx_from_file(model_path);
+        }
+        let weights = model.with_extension("bin");
+        if !weights.is_file() {
+            return Err(SetupError::MissingWeights(weights));
+        }
+        let weights_path = weights.to_str().ok_or(LoadingError::CannotStringifyPath)?;
+        Ok(self.read_network_from_file(model_path, weights_path)?)
+    }
+
+    /// Read a [`CNNNetwork`] from a single ONNX file at `model_path`; ONNX models embed their
+    /// weights, so no weights file is needed. If `model_path` does not point to a file, this returns
+    /// [`SetupError::MissingModel`] with that path before calling into OpenVINO.
+    pub fn read_onnx_from_file(
+        &self,
+        model_path: &str,
+    ) -> std::result::Result<CNNNetwork, SetupError> {
+        if !Path::new(model_path).is_file() {
+            return Err(SetupError::MissingModel(model_path.into()));
+        }
+        let mut instance = std::ptr::null_mut();
+        try_unsafe!(ie_core_read_network(
+            self.shared.instance,
+            cstr!(model_path),
+            cstr!(""),
+            std::ptr::addr_of_mut!(instance)
+        ))?;
+        Ok(CNNNetwork {
+            instance,
+            memory: KeepAlive::new(),
+        })
     }
 
     /// Read a [`CNNNetwork`] from a pair of byte slices: `model_content` contains the XML data
     /// describing the OpenVINO network IR and `weights_content` contains the binary weights.
+    /// `weights_content` may be empty for models that embed their weights (see
+    /// [`Core::read_onnx_from_buffer`]).
     pub fn read_network_from_buffer(
-        &mut self,
+        &self,
         model_content: &[u8],
         weights_content: &[u8],
     ) -> Result<CNNNetwork> {
-        let mut instance = std::ptr::null_mut();
         let weights_desc = TensorDesc::new(Layout::ANY, &[weights_content.len()], Precision::U8);
-        let weights_blob = Blob::new(&weights_desc, weights_content)?;
+        // OpenVINO always expects a weights blob, even an empty one; there is nothing to copy into
+        // it in that case.
+        let mut weights_blob = Blob::allocate(&weights_desc)?;
+        if !weights_content.is_empty() {
+            weights_blob.buffer_mut()?.copy_from_slice(weights_content);
+        }
+        self.read_network_from_weights_blob(model_content, &weights_blob)
+    }
+
+    /// Read a [`CNNNetwork`] from `model_content`, containing the XML data describing the OpenVINO
+    /// network IR, and an existing `weights` [`Blob`] (usually `U8` with a single dimension)
+    /// holding the binary weights. Unlike [`Core::read_network_from_buffer`], this lets the caller
+    /// decide how the weights memory is allocated, e.g., with [`Blob::from_vec`] to avoid copying
+    /// the weights of a very large model. OpenVINO may refer to the weights memory rather than
+    /// copying it, so the returned [`CNNNetwork`], and any [`ExecutableNetwork`] loaded from it,
+    /// keep that memory alive.
+    pub fn read_network_from_weights_blob(
+        &self,
+        model_content: &[u8],
+        weights: &Blob,
+    ) -> Result<CNNNetwork> {
+        let mut instance = std::ptr::null_mut();
         try_unsafe!(ie_core_read_network_from_memory(
-            self.instance,
+            self.shared.instance,
             model_content.as_ptr().cast::<u8>(),
             model_content.len(),
-            weights_blob.instance,
+            weights.instance,
             std::ptr::addr_of_mut!(instance)
         ))?;
-        Ok(CNNNetwork { instance })
+        Ok(CNNNetwork {
+            instance,
+            memory: weights.memory.clone(),
+        })
+    }
+
+    /// Read a [`CNNNetwork`] from the contents of an ONNX model, `model_content`, which embeds its
+    /// weights.
+    pub fn read_onnx_from_buffer(&self, model_content: &[u8]) -> Result<CNNNetwork> {
+        self.read_network_from_buffer(model_content, &[])
     }
 
-    /// Instantiate a [`CNNNetwork`] as an [`ExecutableNetwork`] on the specified `device`.
+    /// Instantiate a [`CNNNetwork`] as an [`ExecutableNetwork`] on the specified `device`. The
+    /// `config` entries (e.g., `PERF_COUNT` or `CPU_THROUGHPUT_STREAMS`) are passed to the device
+    /// plugin; pass an empty map to use the plugin defaults. In particular, the number of CPU
+    /// inference threads is left to OpenVINO's auto-detection unless `config` (or
+    /// [`Core::set_num_threads`]) sets [`keys::CPU_THREADS_NUM`] or
+    /// [`keys::INFERENCE_NUM_THREADS`].
     pub fn load_network(
-        &mut self,
+        &self,
         network: &CNNNetwork,
         device: &str,
+        config: &HashMap<String, String>,
     ) -> Result<ExecutableNetwork> {
+        // Record the names first: failing after the C call would leak the executable network.
+        let names = Arc::new(IoNames::of(network)?);
+        let max_batch_size = max_dynamic_batch_size(config);
         let mut instance = std::ptr::null_mut();
-        // Because `ie_core_load_network` does not allow a null pointer for the configuration, we
-        // construct an empty configuration struct to pass. At some point, it could be good to allow
-        // users to pass a map to this function that gets converted to an `ie_config_t` (TODO).
-        let empty_config = ie_config_t {
-            name: std::ptr::null(),
-            value: std::ptr::null(),
-            next: std::ptr::null_mut(),
-        };
+        // `config` must stay alive until the call returns, since the C API borrows its strings.
+        let config = Config::new(config);
         try_unsafe!(ie_core_load_network(
-            self.instance,
+            self.shared.instance,
             network.instance,
             cstr!(device),
-            std::ptr::addr_of!(empty_config),
+            config.as_ptr(),
             std::ptr::addr_of_mut!(instance)
         ))?;
-        Ok(ExecutableNetwork { instance })
+        Ok(ExecutableNetwork {
+            instance,
+            names,
+            memory: network.memory.clone(),
+            max_batch_size,
+        })
+    }
+
+    /// Instantiate a [`CNNNetwork`] on `device` with dynamic batching enabled (i.e.,
+    /// `DYN_BATCH_ENABLED` and `DYN_BATCH_LIMIT`), so that each [`InferRequest`] created from it
+    /// may compute fewer batch entries than the network's batch size; see
+    /// [`InferRequest::set_batch_size`]. The network's batch size (see
+    /// [`CNNNetwork::set_batch_size`]) must be at least `max_batch`.
+    ///
+    /// [`InferRequest`]: crate::InferRequest
+    /// [`InferRequest::set_batch_size`]: crate::InferRequest::set_batch_size
+    pub fn load_network_dyn_batch(
+        &self,
+        network: &CNNNetwork,
+        device: &str,
+        max_batch: usize,
+    ) -> Result<ExecutableNetwork> {
+        let mut config = HashMap::new();
+        config.insert(keys::DYN_BATCH_ENABLED.to_string(), "YES".to_string());
+        config.insert(keys::DYN_BATCH_LIMIT.to_string(), max_batch.to_string());
+        self.load_network(network, device, &config)
+    }
+
+    /// Instantiate a [`CNNNetwork`] on the `AUTO` device, which selects the most suitable of the
+    /// `priorities` devices (e.g., `["GPU", "CPU"]`, in order of preference) at load time and
+    /// configures it for `hint`. An empty `priorities` lets `AUTO` consider all available devices.
+    pub fn load_network_auto(
+        &self,
+        network: &CNNNetwork,
+        priorities: &[&str],
+        hint: PerformanceHint,
+    ) -> Result<ExecutableNetwork> {
+        let mut config = HashMap::new();
+        config.insert(
+            keys::PERFORMANCE_HINT.to_string(),
+            hint.as_str().to_string(),
+        );
+        if !priorities.is_empty() {
+            config.insert(
+                keys::MULTI_DEVICE_PRIORITIES.to_string(),
+                priorities.join(","),
+            );
+        }
+        self.load_network(network, "AUTO", &config)
+    }
+
+    /// Read a model from files and instantiate it as an [`ExecutableNetwork`] on the specified
+    /// `device` in one call. For single-file models, such as ONNX models with embedded weights, pass
+    /// an empty `weights_path`.
+    ///
+    /// When `weights_path` is empty or is the file OpenVINO would pick itself (i.e., `model_path`
+    /// with a `.bin` extension), this calls OpenVINO's `ie_core_load_network_from_file`, which lets
+    /// the plugin load the network from its cache (see [`Core::set_cache_dir`]). The network is then
+    /// never read on the Rust side, so its input and output names are not recorded: as for
+    /// imported networks (see [`Core::import_network`]), only name-based blob access is available.
+    /// Any other `weights_path` is read with [`Core::read_network_from_file`] and loaded with
+    /// [`Core::load_network`].
+    pub fn compile_model_from_file(
+        &self,
+        model_path: &str,
+        weights_path: &str,
+        device: &str,
+        config: &HashMap<String, String>,
+    ) -> Result<ExecutableNetwork> {
+        if !weights_path.is_empty()
+            && Path::new(weights_path) != Path::new(model_path).with_extension("bin")
+        {
+            let network = self.read_network_from_file(model_path, weights_path)?;
+            return self.load_network(&network, device, config);
+        }
+        let mut instance = std::ptr::null_mut();
+        let max_batch_size = max_dynamic_batch_size(config);
+        let config = Config::new(config);
+        try_unsafe!(ie_core_load_network_from_file(
+            self.shared.instance,
+            cstr!(model_path),
+            cstr!(device),
+            config.as_ptr(),
+            std::ptr::addr_of_mut!(instance)
+        ))?;
+        Ok(ExecutableNetwork {
+            instance,
+            names: Arc::default(),
+            memory: KeepAlive::new(),
+            max_batch_size,
+        })
+    }
+
+    /// Import an [`ExecutableNetwork`] for `device` from the file at `path`, as previously written
+    /// by [`ExecutableNetwork::export`]. This avoids recompiling the network. The exported file does
+    /// not record the network's input and output names, so requests created from an imported
+    /// network only support name-based blob access (e.g., [`InferRequest::get_blob`]).
+    ///
+    /// [`InferRequest::get_blob`]: crate::InferRequest::get_blob
+    pub fn import_network(&self, path: &str, device: &str) -> Result<ExecutableNetwork> {
+        let mut instance = std::ptr::null_mut();
+        let config = Config::new(&HashMap::new());
+        try_unsafe!(ie_core_import_network(
+            self.shared.instance,
+            cstr!(path),
+            cstr!(device),
+            config.as_ptr(),
+            std::ptr::addr_of_mut!(instance)
+        ))?;
+        Ok(ExecutableNetwork {
+            instance,
+            names: Arc::default(),
+            memory: KeepAlive::new(),
+            max_batch_size: None,
+        })
+    }
+
+    /// Import an [`ExecutableNetwork`] for `device` from `content`, as previously produced by
+    /// [`ExecutableNetwork::export_to_vec`] (or read from a file written by
+    /// [`ExecutableNetwork::export`]); see [`Core::import_network`].
+    pub fn import_network_from_buffer(
+        &self,
+        content: &[u8],
+        device: &str,
+    ) -> Result<ExecutableNetwork> {
+        let mut instance = std::ptr::null_mut();
+        let config = Config::new(&HashMap::new());
+        try_unsafe!(ie_core_import_network_from_memory(
+            self.shared.instance,
+            content.as_ptr(),
+            content.len(),
+            cstr!(device),
+            config.as_ptr(),
+            std::ptr::addr_of_mut!(instance)
+        ))?;
+        Ok(ExecutableNetwork {
+            instance,
+            names: Arc::default(),
+            memory: KeepAlive::new(),
+            max_batch_size: None,
+        })
+    }
+
+    /// List the devices available to OpenVINO for inference, e.g., `CPU` or `GPU.0`. These names
+    /// can be used as the `device` passed to [`Core::load_network`].
+    pub fn available_devices(&self) -> Result<Vec<String>> {
+        let mut devices = ie_available_devices_t {
+            devices: std::ptr::null_mut(),
+            num_devices: 0,
+        };
+        try_unsafe!(ie_core_get_available_devices(
+            self.shared.instance,
+            std::ptr::addr_of_mut!(devices)
+        ))?;
+        let names = if devices.devices.is_null() {
+            vec![]
+        } else {
+            let c_names =
+                unsafe { std::slice::from_raw_parts(devices.devices, devices.num_devices) };
+            c_names
+                .iter()
+                .map(|&c_name| {
+                    unsafe { CStr::from_ptr(c_name) }
+                        .to_string_lossy()
+                        .into_owned()
+                })
+                .collect()
+        };
+        unsafe { ie_core_available_devices_free(std::ptr::addr_of_mut!(devices)) };
+        Ok(names)
+    }
+
+    /// Describe each of the [`Core::available_devices`] using its `FULL_DEVICE_NAME` and
+    /// `OPTIMIZATION_CAPABILITIES` metrics; metrics that a device fails to report are skipped.
+    pub fn devices(&self) -> Result<Vec<DeviceInfo>> {
+        Ok(self
+            .available_devices()?
+            .into_iter()
+            .map(|name| {
+                let full_name = self.get_metric(&name, keys::FULL_DEVICE_NAME).ok();
+                let capabilities = self.get_metric(&name, keys::OPTIMIZATION_CAPABILITIES).ok();
+                DeviceInfo::new(name, full_name, capabilities)
+            })
+            .collect())
+    }
+
+    /// Set the `config` entries (e.g., `CPU_BIND_THREAD`) for `device`; these apply to all
+    /// networks subsequently loaded on that device. An empty `device` applies `config` to all
+    /// devices.
+    pub fn set_config(&self, config: &HashMap<String, String>, device: &str) -> Result<()> {
+        let config = Config::new(config);
+        try_unsafe!(ie_core_set_config(
+            self.shared.instance,
+            config.as_ptr(),
+            cstr!(device)
+        ))
+    }
+
+    /// Load the extension shared library at `library_path` (e.g., one implementing custom layers)
+    /// so that networks using its operations can be read and loaded.
+    pub fn add_extension(&self, library_path: &str) -> Result<()> {
+        try_unsafe!(ie_core_add_extension(
+            self.shared.instance,
+            cstr!(library_path),
+            cstr!("")
+        ))
+    }
+
+    /// Register the plugin shared library `plugin_name` (e.g., `libMKLDNNPlugin.so`) to handle
+    /// inference on `device`.
+    pub fn register_plugin(&self, plugin_name: &str, device: &str) -> Result<()> {
+        try_unsafe!(ie_core_register_plugin(
+            self.shared.instance,
+            cstr!(plugin_name),
+            cstr!(device)
+        ))
+    }
+
+    /// Register all of the plugins listed in the `plugins.xml`-style file at `xml_path`.
+    pub fn register_plugins(&self, xml_path: &str) -> Result<()> {
+        try_unsafe!(ie_core_register_plugins(
+            self.shared.instance,
+            cstr!(xml_path)
+        ))
+    }
+
+    /// Enable model caching by setting the `CACHE_DIR` configuration for all devices: subsequent
+    /// calls to [`Core::load_network`] store compiled networks in `dir` and reuse them on later
+    /// loads. Only devices that support import/export (e.g., `GPU`, `MYRIAD`; `CPU` from OpenVINO
+    /// 2021.4) use the cache; other devices silently ignore it. An empty `dir` disables caching.
+    pub fn set_cache_dir(&self, dir: &str) -> Result<()> {
+        self.set_config_entry(keys::CACHE_DIR, dir, "")
+    }
+
+    /// Set the number of threads the `CPU` device uses for inference (`CPU_THREADS_NUM`); `0`, the
+    /// default, lets OpenVINO choose based on the available cores.
+    pub fn set_num_threads(&self, num_threads: usize) -> Result<()> {
+        self.set_config_entry(keys::CPU_THREADS_NUM, &num_threads.to_string(), "CPU")
+    }
+
+    /// Set how the `CPU` device pins its inference threads (`CPU_BIND_THREAD`).
+    pub fn set_thread_binding(&self, binding: ThreadBinding) -> Result<()> {
+        self.set_config_entry(keys::CPU_BIND_THREAD, binding.as_str(), "CPU")
+    }
+
+    /// Let `device` choose its inference streams and threads for the given `hint`
+    /// (`PERFORMANCE_HINT`). This supersedes manually setting device-specific options such as
+    /// `CPU_THROUGHPUT_STREAMS`. Performance hints are supported from OpenVINO 2022.1; older
+    /// versions reject the configuration key.
+    pub fn set_performance_hint(&self, hint: PerformanceHint, device: &str) -> Result<()> {
+        self.set_config_entry(keys::PERFORMANCE_HINT, hint.as_str(), device)
+    }
+
+    /// Limit the number of inference requests that [`PerformanceHint::Throughput`] optimizes for
+    /// on `device` (`PERFORMANCE_HINT_NUM_REQUESTS`), e.g., when the application never runs more
+    /// than `num_requests` requests at once.
+    pub fn set_performance_hint_num_requests(
+        &self,
+        num_requests: usize,
+        device: &str,
+    ) -> Result<()> {
+        self.set_config_entry(
+            keys::PERFORMANCE_HINT_NUM_REQUESTS,
+            &num_requests.to_string(),
+            device,
+        )
+    }
+
+    /// Set how verbosely the `device` plugin logs its diagnostics (`LOG_LEVEL`). OpenVINO's C API
+    /// offers no logging callback, so these messages cannot be redirected (e.g., to the `log`
+    /// crate) and are written by the plugin itself, usually to standard output or error; lowering
+    /// the level is the only way to quiet them.
+    pub fn set_log_level(&self, level: LogLevel, device: &str) -> Result<()> {
+        self.set_config_entry(keys::LOG_LEVEL, level.as_str(), device)
+    }
+
+    /// Set a single configuration `key` to `value` for `device`; see [`Core::set_config`].
+    fn set_config_entry(&self, key: &str, value: &str, device: &str) -> Result<()> {
+        let mut config = HashMap::new();
+        config.insert(key.to_string(), value.to_string());
+        self.set_config(&config, device)
+    }
+
+    /// Retrieve the value of the configuration `key` for `device`.
+    pub fn get_config(&self, device: &str, key: &str) -> Result<String> {
+        let mut param = empty_param();
+        try_unsafe!(ie_core_get_config(
+            self.shared.instance,
+            cstr!(device),
+            cstr!(key),
+            std::ptr::addr_of_mut!(param)
+        ))?;
+        // Safety: OpenVINO returns all configuration values as strings.
+        Ok(unsafe { take_string_param(&mut param) })
+    }
+
+    /// Retrieve the value of the read-only `metric` (e.g., `FULL_DEVICE_NAME` or
+    /// `RANGE_FOR_ASYNC_INFER_REQUESTS`) for `device`, converted to a string. Ranges are formatted
+    /// as `"min..max step s"` (or `"min..max"` for `RANGE_FOR_STREAMS`) and lists are separated by
+    /// commas. Since the C API does not say which kind of value a metric has, only the metrics
+    /// named in [`keys`] can be read; others return
+    /// [`InferenceError::NotImplemented`](crate::InferenceError::NotImplemented).
+    pub fn get_metric(&self, device: &str, metric: &str) -> Result<String> {
+        let kind = MetricKind::of(metric)?;
+        let mut param = empty_param();
+        try_unsafe!(ie_core_get_metric(
+            self.shared.instance,
+            cstr!(device),
+            cstr!(metric),
+            std::ptr::addr_of_mut!(param)
+        ))?;
+        Ok(unsafe { take_metric_param(kind, &mut param) })
+    }
+
+    /// Retrieve the version of each plugin backing `device`; a composite device (e.g.,
+    /// `HETERO:GPU,CPU`) will contain an entry for each underlying device.
+    pub fn get_versions(&self, device: &str) -> Result<HashMap<String, Version>> {
+        let mut versions = ie_core_versions_t {
+            versions: std::ptr::null_mut(),
+            num_vers: 0,
+        };
+        try_unsafe!(ie_core_get_versions(
+            self.shared.instance,
+            cstr!(device),
+            std::ptr::addr_of_mut!(versions)
+        ))?;
+        let map = if versions.versions.is_null() {
+            HashMap::new()
+        } else {
+            unsafe { std::slice::from_raw_parts(versions.versions, versions.num_vers) }
+                .iter()
+                .map(|version| unsafe {
+                    (
+                        string_from_ptr(version.device_name),
+                        Version::from_raw(version),
+                    )
+                })
+                .collect()
+        };
+        unsafe { ie_core_versions_free(std::ptr::addr_of_mut!(versions)) };
+        Ok(map)
+    }
+}
+
+/// Describe how the `CPU` device pins its inference threads to cores; see
+/// [`Core::set_thread_binding`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ThreadBinding {
+    /// Pin threads to cores (`YES`); best for static, latency-sensitive workloads.
+    Cores,
+    /// Pin threads to NUMA nodes (`NUMA`).
+    Numa,
+    /// Pin threads according to the core types of hybrid CPUs (`HYBRID_AWARE`).
+    HybridAware,
+    /// Do not pin threads (`NO`).
+    None,
+}
+
+impl ThreadBinding {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Cores => "YES",
+            Self::Numa => "NUMA",
+            Self::HybridAware => "HYBRID_AWARE",
+            Self::None => "NO",
+        }
+    }
+}
+
+/// Describe what a device should optimize for; see [`Core::set_performance_hint`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PerformanceHint {
+    /// Minimize the time of a single inference request (`LATENCY`).
+    Latency,
+    /// Maximize the number of inferences per second across parallel requests (`THROUGHPUT`).
+    Throughput,
+    /// Maximize throughput across all the devices of a composite device, such as `AUTO`
+    /// (`CUMULATIVE_THROUGHPUT`).
+    CumulativeThroughput,
+}
+
+impl PerformanceHint {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Latency => "LATENCY",
+            Self::Throughput => "THROUGHPUT",
+            Self::CumulativeThroughput => "CUMULATIVE_THROUGHPUT",
+        }
+    }
+}
+
+/// Describe which diagnostics a device plugin logs; see [`Core::set_log_level`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogLevel {
+    /// Log nothing (`LOG_NONE`).
+    None,
+    /// Log errors only (`LOG_ERROR`).
+    Error,
+    /// Log errors and warnings (`LOG_WARNING`).
+    Warning,
+    /// Log errors, warnings and informational messages (`LOG_INFO`).
+    Info,
+    /// Log debugging messages too (`LOG_DEBUG`).
+    Debug,
+    /// Log everything, including tracing messages (`LOG_TRACE`).
+    Trace,
+}
+
+impl LogLevel {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::None => "LOG_NONE",
+            Self::Error => "LOG_ERROR",
+            Self::Warning => "LOG_WARNING",
+            Self::Info => "LOG_INFO",
+            Self::Debug => "LOG_DEBUG",
+            Self::Trace => "LOG_TRACE",
+        }
+    }
+}
+
+/// Describe the version of an OpenVINO plugin, as returned by [`Core::get_versions`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Version {
+    /// The major version of the plugin API.
+    pub major: usize,
+    /// The minor version of the plugin API.
+    pub minor: usize,
+    /// The build identifier of the plugin.
+    pub build_number: String,
+    /// A description of the plugin.
+    pub description: String,
+}
+
+impl Version {
+    /// Copy the version information out of the C structure.
+    unsafe fn from_raw(version: &ie_core_version_t) -> Self {
+        Self {
+            major: version.major,
+            minor: version.minor,
+            build_number: string_from_ptr(version.build_number),
+            description: string_from_ptr(version.description),
+        }
     }
 }
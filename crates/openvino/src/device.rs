@@ -0,0 +1,150 @@
+//! Build the device strings passed to [`Core::load_network`](crate::Core::load_network), including
+//! the composite `MULTI`, `HETERO` and `AUTO` devices.
+
+use crate::error::DeviceError;
+
+/// Describe a device available to OpenVINO, as returned by
+/// [`Core::devices`](crate::Core::devices). Metrics that a device does not report are left empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DeviceInfo {
+    /// The device name to pass to [`Core::load_network`](crate::Core::load_network), e.g., `GPU.0`.
+    pub name: String,
+    /// The human-readable device name (`FULL_DEVICE_NAME`).
+    pub full_name: Option<String>,
+    /// The inference precisions the device supports, among `FP32`, `BF16`, `FP16`, `INT8` and
+    /// `BIN`.
+    pub precisions: Vec<String>,
+    /// All of the device's optimization capabilities (`OPTIMIZATION_CAPABILITIES`), e.g.,
+    /// `WINOGRAD` or `EXPORT_IMPORT`, including its precisions.
+    pub optimization_capabilities: Vec<String>,
+}
+
+impl DeviceInfo {
+    /// The capabilities in `OPTIMIZATION_CAPABILITIES` that name inference precisions.
+    const PRECISIONS: [&'static str; 5] = ["FP32", "BF16", "FP16", "INT8", "BIN"];
+
+    /// Assemble the information for device `name` from its raw metric values.
+    pub(crate) fn new(
+        name: String,
+        full_name: Option<String>,
+        optimization_capabilities: Option<String>,
+    ) -> Self {
+        // OpenVINO returns lists as a single string separated by commas.
+        let optimization_capabilities: Vec<String> = optimization_capabilities
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|capability| !capability.is_empty())
+            .map(ToString::to_string)
+            .collect();
+        let precisions = optimization_capabilities
+            .iter()
+            .filter(|capability| Self::PRECISIONS.contains(&capability.as_str()))
+            .cloned()
+            .collect();
+        Self {
+            name,
+            full_name,
+            precisions,
+            optimization_capabilities,
+        }
+    }
+}
+
+/// Construct device strings (e.g., `"MULTI:GPU,CPU"`) from lists of device names, checking that
+/// the names are not empty. See the OpenVINO documentation on the
+/// [multi-device](https://docs.openvinotoolkit.org/latest/openvino_docs_IE_DG_supported_plugins_MULTI.html)
+/// and [heterogeneous](https://docs.openvinotoolkit.org/latest/openvino_docs_IE_DG_supported_plugins_HETERO.html)
+/// plugins.
+///
+/// ```
+/// use openvino::DeviceBuilder;
+/// assert_eq!(DeviceBuilder::multi(&["GPU", "CPU"]).unwrap(), "MULTI:GPU,CPU");
+/// assert!(DeviceBuilder::hetero(&[]).is_err());
+/// ```
+pub struct DeviceBuilder;
+
+impl DeviceBuilder {
+    /// Use a single device, e.g., `CPU`.
+    pub fn single(device: &str) -> Result<String, DeviceError> {
+        check_name(device)?;
+        Ok(device.to_string())
+    }
+
+    /// Run inference requests in parallel across all of `devices`, in order of priority.
+    pub fn multi(devices: &[&str]) -> Result<String, DeviceError> {
+        composite("MULTI", devices)
+    }
+
+    /// Split the network's layers across `devices`, falling back to later devices for layers that
+    /// earlier devices do not support.
+    pub fn hetero(devices: &[&str]) -> Result<String, DeviceError> {
+        composite("HETERO", devices)
+    }
+
+    /// Let OpenVINO select the most suitable device among `devices`.
+    pub fn auto(devices: &[&str]) -> Result<String, DeviceError> {
+        composite("AUTO", devices)
+    }
+}
+
+fn composite(prefix: &str, devices: &[&str]) -> Result<String, DeviceError> {
+    if devices.is_empty() {
+        return Err(DeviceError::NoDevices);
+    }
+    for device in devices {
+        check_name(device)?;
+    }
+    Ok(format!("{prefix}:{}", devices.join(",")))
+}
+
+fn check_name(device: &str) -> Result<(), DeviceError> {
+    if device.trim().is_empty() {
+        Err(DeviceError::EmptyName)
+    } else {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn device_info() {
+        let info = DeviceInfo::new(
+            "GPU.0".to_string(),
+            Some("Intel(R) UHD Graphics".to_string()),
+            Some("FP32, BIN, FP16, INT8, EXPORT_IMPORT".to_string()),
+        );
+        assert_eq!(info.precisions, ["FP32", "BIN", "FP16", "INT8"]);
+        assert_eq!(info.optimization_capabilities.len(), 5);
+
+        let unreported = DeviceInfo::new("CPU".to_string(), None, None);
+        assert!(unreported.precisions.is_empty());
+    }
+
+    #[test]
+    fn device_strings() {
+        assert_eq!(DeviceBuilder::single("CPU").unwrap(), "CPU");
+        assert_eq!(
+            DeviceBuilder::multi(&["GPU", "CPU"]).unwrap(),
+            "MULTI:GPU,CPU"
+        );
+        assert_eq!(
+            DeviceBuilder::hetero(&["GPU", "CPU"]).unwrap(),
+            "HETERO:GPU,CPU"
+        );
+        assert_eq!(DeviceBuilder::auto(&["CPU"]).unwrap(), "AUTO:CPU");
+    }
+
+    #[test]
+    fn invalid_devices() {
+        assert_eq!(DeviceBuilder::multi(&[]), Err(DeviceError::NoDevices));
+        assert_eq!(DeviceBuilder::single(""), Err(DeviceError::EmptyName));
+        assert_eq!(
+            DeviceBuilder::hetero(&["GPU", " "]),
+            Err(DeviceError::EmptyName)
+        );
+    }
+}
@@ -1,3 +1,5 @@
+use crate::{Layout, Precision, TensorDesc};
+use std::path::PathBuf;
 use thiserror::Error;
 
 /// Enumerate errors returned by the OpenVINO implementation. See
@@ -6,32 +8,39 @@ use thiserror::Error;
 #[allow(missing_docs)]
 #[derive(Debug, Error, PartialEq, Eq)]
 pub enum InferenceError {
-    #[error("general error")]
+    #[error("general error (status code -1)")]
     GeneralError,
-    #[error("not implemented")]
+    #[error("not implemented (status code -2)")]
     NotImplemented,
-    #[error("network not loaded")]
+    #[error("network not loaded (status code -3)")]
     NetworkNotLoaded,
-    #[error("parameter mismatch")]
+    #[error("parameter mismatch (status code -4)")]
     ParameterMismatch,
-    #[error("not found")]
+    #[error("not found (status code -5)")]
     NotFound,
-    #[error("out of bounds")]
+    #[error("out of bounds (status code -6)")]
     OutOfBounds,
-    #[error("unexpected")]
+    #[error("unexpected (status code -7)")]
     Unexpected,
-    #[error("request busy")]
+    #[error("request busy (status code -8)")]
     RequestBusy,
-    #[error("result not ready")]
+    #[error("result not ready (status code -9)")]
     ResultNotReady,
-    #[error("not allocated")]
+    #[error("not allocated (status code -10)")]
     NotAllocated,
-    #[error("infer not started")]
+    #[error("infer not started (status code -11)")]
     InferNotStarted,
-    #[error("network not ready")]
-    NetworkNotReady,
-    #[error("undefined error code: {0}")]
+    #[error("network not read (status code -12)")]
+    NetworkNotRead,
+    #[error("infer cancelled (status code -13)")]
+    InferCancelled,
+    #[error("undefined error (status code {0})")]
     Undefined(i32),
+    /// Returned by [`InferRequest::infer_timeout`](crate::InferRequest::infer_timeout); OpenVINO
+    /// itself reports an expired wait as [`InferenceError::ResultNotReady`], whose status code
+    /// this shares.
+    #[error("inference timed out (status code -9)")]
+    TimedOut,
 }
 
 impl InferenceError {
@@ -54,12 +63,37 @@ impl InferenceError {
             openvino_sys::IEStatusCode_RESULT_NOT_READY => Err(ResultNotReady),
             openvino_sys::IEStatusCode_NOT_ALLOCATED => Err(NotAllocated),
             openvino_sys::IEStatusCode_INFER_NOT_STARTED => Err(InferNotStarted),
-            openvino_sys::IEStatusCode_NETWORK_NOT_READ => Err(NetworkNotReady),
+            openvino_sys::IEStatusCode_NETWORK_NOT_READ => Err(NetworkNotRead),
+            openvino_sys::IEStatusCode_INFER_CANCELLED => Err(InferCancelled),
             _ => Err(Undefined(error_code)),
         }
     }
 }
 
+impl InferenceError {
+    /// Retrieve the numeric OpenVINO status code corresponding to this error.
+    pub fn code(&self) -> i32 {
+        #[allow(clippy::enum_glob_use)]
+        use InferenceError::*;
+        match self {
+            GeneralError => openvino_sys::IEStatusCode_GENERAL_ERROR,
+            NotImplemented => openvino_sys::IEStatusCode_NOT_IMPLEMENTED,
+            NetworkNotLoaded => openvino_sys::IEStatusCode_NETWORK_NOT_LOADED,
+            ParameterMismatch => openvino_sys::IEStatusCode_PARAMETER_MISMATCH,
+            NotFound => openvino_sys::IEStatusCode_NOT_FOUND,
+            OutOfBounds => openvino_sys::IEStatusCode_OUT_OF_BOUNDS,
+            Unexpected => openvino_sys::IEStatusCode_UNEXPECTED,
+            RequestBusy => openvino_sys::IEStatusCode_REQUEST_BUSY,
+            ResultNotReady | TimedOut => openvino_sys::IEStatusCode_RESULT_NOT_READY,
+            NotAllocated => openvino_sys::IEStatusCode_NOT_ALLOCATED,
+            InferNotStarted => openvino_sys::IEStatusCode_INFER_NOT_STARTED,
+            NetworkNotRead => openvino_sys::IEStatusCode_NETWORK_NOT_READ,
+            InferCancelled => openvino_sys::IEStatusCode_INFER_CANCELLED,
+            Undefined(code) => *code,
+        }
+    }
+}
+
 /// Enumerate setup failures: in some cases, this library will call library-loading code that may
 /// fail in a different way (i.e., [`LoadingError`]) than the calls to the OpenVINO libraries (i.e.,
 /// [`InferenceError`]).
@@ -70,18 +104,163 @@ pub enum SetupError {
     Inference(#[from] InferenceError),
     #[error("library loading error")]
     Loading(#[from] LoadingError),
+    #[error("unable to write the plugin configuration to a temporary file")]
+    PluginConfig(#[from] std::io::Error),
+    #[error("unable to read the exported network from a temporary file")]
+    Export(std::io::Error),
+    #[error("unable to find the network model at {0:?}")]
+    MissingModel(std::path::PathBuf),
+    #[error("unable to find the network weights, expected at {0:?}")]
+    MissingWeights(std::path::PathBuf),
 }
 
 /// Enumerate the ways that library loading can fail.
 #[allow(missing_docs)]
 #[derive(Debug, Error)]
 pub enum LoadingError {
-    #[error("system failed to load shared libraries (see https://github.com/intel/openvino-rs/blob/main/crates/openvino-finder): {0}")]
-    SystemFailure(String),
+    #[error("system failed to load the `{library}` shared library (see https://github.com/intel/openvino-rs/blob/main/crates/openvino-finder): {reason}{}", describe_searched(.searched))]
+    SystemFailure {
+        library: String,
+        searched: Vec<PathBuf>,
+        reason: String,
+    },
     #[error("cannot find path to shared libraries (see https://github.com/intel/openvino-rs/blob/main/crates/openvino-finder)")]
     CannotFindLibraryPath,
     #[error("cannot find path to XML plugin configuration (see https://github.com/intel/openvino-rs/blob/main/crates/openvino-finder)")]
     CannotFindPluginPath,
     #[error("unable to convert path to a UTF-8 string (see https://doc.rust-lang.org/std/path/struct.Path.html#method.to_str)")]
     CannotStringifyPath,
+    #[error("no OpenVINO plugins found: set `OPENVINO_PLUGINS_XML` to the path of the `plugins.xml` file or pass it to `Core::new` (see https://github.com/intel/openvino-rs/blob/main/crates/openvino-finder)")]
+    NoPluginsFound,
+}
+
+impl LoadingError {
+    /// Describe the failure to load the `openvino_c` shared library with `reason`; if the library
+    /// cannot be found, this also records the paths searched for it.
+    pub(crate) fn system_failure(reason: String) -> Self {
+        const LIBRARY: &str = "openvino_c";
+        LoadingError::SystemFailure {
+            library: LIBRARY.to_string(),
+            searched: openvino_finder::try_find(LIBRARY).err().unwrap_or_default(),
+            reason,
+        }
+    }
+}
+
+fn describe_searched(searched: &[PathBuf]) -> String {
+    if searched.is_empty() {
+        String::new()
+    } else {
+        let paths: Vec<_> = searched
+            .iter()
+            .map(|path| path.display().to_string())
+            .collect();
+        format!("; searched: {}", paths.join(", "))
+    }
+}
+
+/// Enumerate the ways that accessing the contents of a [`Blob`](crate::Blob) can fail, in
+/// addition to the [`InferenceError`]s returned by OpenVINO itself.
+#[allow(missing_docs)]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum BlobError {
+    #[error("inference error")]
+    Inference(#[from] InferenceError),
+    #[error(
+        "precision mismatch: the blob contains {actual:?} elements but {expected:?} were requested"
+    )]
+    PrecisionMismatch {
+        expected: Precision,
+        actual: Precision,
+    },
+    #[error("tensor description mismatch: expected a {expected} blob but found {actual}")]
+    DescMismatch {
+        expected: Box<TensorDesc>,
+        actual: Box<TensorDesc>,
+    },
+    #[error(
+        "rank mismatch: a {layout:?} tensor has {expected} dimensions but {actual} were provided"
+    )]
+    RankMismatch {
+        layout: Layout,
+        expected: usize,
+        actual: usize,
+    },
+    #[error("layout mismatch: the blob has a {actual:?} layout but {expected:?} was required")]
+    LayoutMismatch { expected: Layout, actual: Layout },
+    #[error("size mismatch: the blob requires {expected} bytes but {actual} were provided")]
+    SizeMismatch { expected: usize, actual: usize },
+    #[error("unable to read the blob contents: {0}")]
+    Read(std::io::ErrorKind),
+    #[error("the blob size ({byte_len} bytes) is not a multiple of the element size ({element_size} bytes)")]
+    InvalidSize {
+        byte_len: usize,
+        element_size: usize,
+    },
+    #[error("the blob buffer is not aligned to {0} bytes")]
+    Misaligned(usize),
+    #[error("unsupported operation on a compound blob (e.g., NV12)")]
+    Compound,
+    #[error(
+        "the region of interest does not fit within the blob's {height}x{width} spatial dimensions"
+    )]
+    RoiOutOfBounds { height: usize, width: usize },
+    #[error("batch index {index} is out of bounds for a batch of {batch}")]
+    BatchOutOfBounds { index: usize, batch: usize },
+    #[error("the blob has no batch dimension")]
+    NotBatched,
+    #[error("shape mismatch: expected {expected:?}, found {actual:?}")]
+    ShapeMismatch {
+        expected: Vec<usize>,
+        actual: Vec<usize>,
+    },
+}
+
+/// Enumerate the ways that building a device string with
+/// [`DeviceBuilder`](crate::DeviceBuilder) can fail.
+#[allow(missing_docs)]
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DeviceError {
+    #[error("no devices were given")]
+    NoDevices,
+    #[error("device names must not be empty")]
+    EmptyName,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn status_codes_round_trip() {
+        assert_eq!(InferenceError::from(0), Ok(()));
+        for code in (-13..0).chain([-42]) {
+            let error = InferenceError::from(code).unwrap_err();
+            assert_eq!(error.code(), code);
+            assert!(error.to_string().contains(&code.to_string()));
+        }
+        assert_eq!(
+            InferenceError::from(-12),
+            Err(InferenceError::NetworkNotRead)
+        );
+        assert_eq!(
+            InferenceError::TimedOut.code(),
+            InferenceError::ResultNotReady.code()
+        );
+    }
+
+    #[test]
+    fn system_failure_lists_searched_paths() {
+        let error = LoadingError::SystemFailure {
+            library: "openvino_c".to_string(),
+            searched: vec![
+                PathBuf::from("/a/libopenvino_c.so"),
+                PathBuf::from("/b/libopenvino_c.so"),
+            ],
+            reason: "not found".to_string(),
+        };
+        let message = error.to_string();
+        assert!(message.contains("`openvino_c`"));
+        assert!(message.ends_with("not found; searched: /a/libopenvino_c.so, /b/libopenvino_c.so"));
+    }
 }
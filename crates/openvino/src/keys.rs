@@ -0,0 +1,85 @@
+//! Name the common configuration keys (for [`Core::set_config`](crate::Core::set_config) and
+//! [`Core::get_config`](crate::Core::get_config)) and metrics (for
+//! [`Core::get_metric`](crate::Core::get_metric) and
+//! [`ExecutableNetwork::get_metric`](crate::ExecutableNetwork::get_metric)). Plugins may support
+//! further keys; query a device's `SUPPORTED_CONFIG_KEYS` and `SUPPORTED_METRICS` to list them.
+//!
+//! ```
+//! use openvino::keys;
+//! use std::collections::HashMap;
+//! let mut config = HashMap::new();
+//! config.insert(keys::CPU_THROUGHPUT_STREAMS.to_string(), "4".to_string());
+//! ```
+
+// Configuration keys supported by most devices.
+
+/// Collect per-layer performance counters: `YES` or `NO`.
+pub const PERF_COUNT: &str = "PERF_COUNT";
+/// The directory in which to cache compiled networks; see
+/// [`Core::set_cache_dir`](crate::Core::set_cache_dir).
+pub const CACHE_DIR: &str = "CACHE_DIR";
+/// The high-level performance goal; see
+/// [`Core::set_performance_hint`](crate::Core::set_performance_hint).
+pub const PERFORMANCE_HINT: &str = "PERFORMANCE_HINT";
+/// The number of inference requests the performance hint optimizes for.
+pub const PERFORMANCE_HINT_NUM_REQUESTS: &str = "PERFORMANCE_HINT_NUM_REQUESTS";
+/// Allow inference requests to run with a smaller batch size than the network's: `YES` or `NO`.
+pub const DYN_BATCH_ENABLED: &str = "DYN_BATCH_ENABLED";
+/// The maximum batch size used with dynamic batching.
+pub const DYN_BATCH_LIMIT: &str = "DYN_BATCH_LIMIT";
+/// Run asynchronous requests of different networks one at a time: `YES` or `NO`.
+pub const EXCLUSIVE_ASYNC_REQUESTS: &str = "EXCLUSIVE_ASYNC_REQUESTS";
+/// The number of parallel inference streams (OpenVINO 2022 and later), or `AUTO`.
+pub const NUM_STREAMS: &str = "NUM_STREAMS";
+/// The ID of the device to use among several of the same kind, e.g., `0` for `GPU.0`.
+pub const DEVICE_ID: &str = "DEVICE_ID";
+/// The plugin's logging level, e.g., `LOG_WARNING`; see
+/// [`Core::set_log_level`](crate::Core::set_log_level).
+pub const LOG_LEVEL: &str = "LOG_LEVEL";
+
+// Configuration keys for the `CPU` device.
+
+/// The number of inference threads; see [`Core::set_num_threads`](crate::Core::set_num_threads).
+pub const CPU_THREADS_NUM: &str = "CPU_THREADS_NUM";
+/// The number of inference threads (OpenVINO 2022 and later); `0` lets OpenVINO decide.
+pub const INFERENCE_NUM_THREADS: &str = "INFERENCE_NUM_THREADS";
+/// How inference threads are pinned; see
+/// [`Core::set_thread_binding`](crate::Core::set_thread_binding).
+pub const CPU_BIND_THREAD: &str = "CPU_BIND_THREAD";
+/// The number of parallel inference streams, or `CPU_THROUGHPUT_AUTO`.
+pub const CPU_THROUGHPUT_STREAMS: &str = "CPU_THROUGHPUT_STREAMS";
+/// Run inference in `bfloat16` where supported: `YES` or `NO`.
+pub const ENFORCE_BF16: &str = "ENFORCE_BF16";
+
+// Configuration keys for the `GPU` device.
+
+/// The number of parallel inference streams, or `GPU_THROUGHPUT_AUTO`.
+pub const GPU_THROUGHPUT_STREAMS: &str = "GPU_THROUGHPUT_STREAMS";
+
+// Configuration keys for the `MULTI` and `AUTO` devices.
+
+/// The candidate devices, in order of priority, e.g., `GPU,CPU`.
+pub const MULTI_DEVICE_PRIORITIES: &str = "MULTI_DEVICE_PRIORITIES";
+
+// Metrics.
+
+/// The devices of a plugin, e.g., `0` and `1` for `GPU.0` and `GPU.1`.
+pub const AVAILABLE_DEVICES: &str = "AVAILABLE_DEVICES";
+/// The metrics a device or executable network reports.
+pub const SUPPORTED_METRICS: &str = "SUPPORTED_METRICS";
+/// The configuration keys a device or executable network accepts.
+pub const SUPPORTED_CONFIG_KEYS: &str = "SUPPORTED_CONFIG_KEYS";
+/// The human-readable device name.
+pub const FULL_DEVICE_NAME: &str = "FULL_DEVICE_NAME";
+/// The device's architecture, e.g., `intel64`.
+pub const DEVICE_ARCHITECTURE: &str = "DEVICE_ARCHITECTURE";
+/// The device's optimization capabilities, e.g., `FP16` or `EXPORT_IMPORT`.
+pub const OPTIMIZATION_CAPABILITIES: &str = "OPTIMIZATION_CAPABILITIES";
+/// The range of asynchronous requests a device can run, formatted as `"min..max step s"`.
+pub const RANGE_FOR_ASYNC_INFER_REQUESTS: &str = "RANGE_FOR_ASYNC_INFER_REQUESTS";
+/// The range of inference streams a device supports, formatted as `"min..max"`.
+pub const RANGE_FOR_STREAMS: &str = "RANGE_FOR_STREAMS";
+/// The name of an executable network.
+pub const NETWORK_NAME: &str = "NETWORK_NAME";
+/// The number of inference requests an executable network runs best with.
+pub const OPTIMAL_NUMBER_OF_INFER_REQUESTS: &str = "OPTIMAL_NUMBER_OF_INFER_REQUESTS";
@@ -6,7 +6,8 @@
 //!
 //! Check the loaded version of OpenVINO:
 //! ```
-//! assert!(openvino::version().starts_with("2"))
+//! let version = openvino::version().expect("to have an OpenVINO library available");
+//! assert!(version.starts_with("2"))
 //! ```
 //!
 //! Most interaction with OpenVINO begins with instantiating a [Core]:
@@ -25,38 +26,48 @@
     clippy::len_without_is_empty
 )]
 
+mod benchmark;
 mod blob;
 mod core;
+mod device;
 mod error;
+pub mod keys;
 mod network;
+mod pool;
+pub mod postprocess;
+mod precision;
+pub mod prelude;
 mod request;
 mod tensor_desc;
 mod util;
 
-pub use crate::core::Core;
-pub use blob::Blob;
-pub use error::{InferenceError, LoadingError, SetupError};
+pub use crate::core::{Core, LogLevel, PerformanceHint, ThreadBinding, Version};
+pub use benchmark::BenchmarkResult;
+pub use blob::{Blob, Roi};
+pub use device::{DeviceBuilder, DeviceInfo};
+pub use error::{BlobError, DeviceError, InferenceError, LoadingError, SetupError};
 pub use network::{CNNNetwork, ExecutableNetwork};
+pub use pool::InferPool;
 // Re-publish some OpenVINO enums with a conventional Rust naming (see
 // `crates/openvino-sys/build.rs`).
 pub use openvino_sys::{
-    layout_e as Layout, precision_e as Precision, resize_alg_e as ResizeAlgorithm,
+    colorformat_e as ColorFormat, layout_e as Layout, precision_e as Precision,
+    resize_alg_e as ResizeAlgorithm, ParseEnumError,
 };
-pub use request::InferRequest;
+pub use precision::PrecisionType;
+pub use request::{InferRequest, Outputs, WaitStatus};
 pub use tensor_desc::TensorDesc;
 
-/// Emit the version string of the OpenVINO C API backing this implementation.
-///
-/// # Panics
-///
-/// Panics if no OpenVINO library can be found.
-pub fn version() -> String {
+/// Emit the version string of the OpenVINO C API backing this implementation, e.g., to log which
+/// OpenVINO runtime is in use. This loads the OpenVINO shared libraries if necessary, which fails
+/// with a [`LoadingError`] if no OpenVINO library can be found.
+pub fn version() -> Result<String, LoadingError> {
     use std::ffi::CStr;
-    openvino_sys::load().expect("to have an OpenVINO shared library available");
+    openvino_sys::load().map_err(LoadingError::system_failure)?;
     let mut ie_version = unsafe { openvino_sys::ie_c_api_version() };
     let str_version = unsafe { CStr::from_ptr(ie_version.api_version) }
         .to_string_lossy()
         .into_owned();
     unsafe { openvino_sys::ie_version_free(std::ptr::addr_of_mut!(ie_version)) };
-    str_version
+    Ok(str_version)
 }
@@ -2,22 +2,41 @@
 //!  - [`CNNNetwork`] is the OpenVINO representation of a neural network
 //!  - [`ExecutableNetwork`] is the compiled representation of a [`CNNNetwork`] for a device.
 
+use crate::blob::KeepAlive;
 use crate::request::InferRequest;
-use crate::{cstr, drop_using_function, try_unsafe, util::Result};
-use crate::{Layout, Precision, ResizeAlgorithm};
+use crate::tensor_desc::TensorDesc;
+use crate::util::{empty_param, take_metric_param, take_string_param, MetricKind, TemporaryDir};
+use crate::{cstr, drop_using_function, keys, try_unsafe, util::Result, InferenceError};
+use crate::{ColorFormat, Layout, LoadingError, Precision, ResizeAlgorithm, SetupError};
 use openvino_sys::{
-    ie_exec_network_create_infer_request, ie_exec_network_free, ie_executable_network_t,
-    ie_network_free, ie_network_get_input_name, ie_network_get_inputs_number,
-    ie_network_get_output_name, ie_network_get_outputs_number, ie_network_name_free,
-    ie_network_set_input_layout, ie_network_set_input_precision,
-    ie_network_set_input_resize_algorithm, ie_network_set_output_precision, ie_network_t,
+    ie_core_export_network, ie_exec_network_create_infer_request, ie_exec_network_free,
+    ie_exec_network_get_config, ie_exec_network_get_metric, ie_executable_network_t,
+    ie_network_free, ie_network_get_color_format, ie_network_get_input_dims,
+    ie_network_get_input_layout, ie_network_get_input_name, ie_network_get_input_precision,
+    ie_network_get_input_resize_algorithm, ie_network_get_input_shapes,
+    ie_network_get_inputs_number, ie_network_get_name, ie_network_get_output_dims,
+    ie_network_get_output_layout, ie_network_get_output_name, ie_network_get_output_precision,
+    ie_network_get_outputs_number, ie_network_input_shapes_free, ie_network_name_free,
+    ie_network_reshape, ie_network_set_color_format, ie_network_set_input_layout,
+    ie_network_set_input_precision, ie_network_set_input_resize_algorithm,
+    ie_network_set_output_layout, ie_network_set_output_precision, ie_network_t, input_shapes_t,
 };
+use std::collections::HashMap;
 use std::ffi::CStr;
+use std::fs;
+use std::io;
+use std::mem::MaybeUninit;
+use std::sync::Arc;
 
 /// See
 /// [`CNNNetwork`](https://docs.openvinotoolkit.org/latest/classInferenceEngine_1_1CNNNetwork.html).
+///
+/// Only the network's inputs and outputs can be inspected: OpenVINO's C API does not expose the
+/// network's internal layers or topology.
 pub struct CNNNetwork {
     pub(crate) instance: *mut ie_network_t,
+    // The weights memory OpenVINO may refer to (see `Core::read_network_from_weights_blob`).
+    pub(crate) memory: KeepAlive,
 }
 drop_using_function!(CNNNetwork, ie_network_free);
 
@@ -25,6 +44,29 @@ unsafe impl Send for CNNNetwork {}
 unsafe impl Sync for CNNNetwork {}
 
 impl CNNNetwork {
+    /// Retrieve the name of the network.
+    pub fn name(&self) -> Result<String> {
+        let mut c_name = std::ptr::null_mut();
+        try_unsafe!(ie_network_get_name(
+            self.instance,
+            std::ptr::addr_of_mut!(c_name)
+        ))?;
+        let rust_name = unsafe { CStr::from_ptr(c_name) }
+            .to_string_lossy()
+            .into_owned();
+        unsafe { ie_network_name_free(std::ptr::addr_of_mut!(c_name)) };
+        debug_assert!(c_name.is_null());
+        Ok(rust_name)
+    }
+
+    /// Write the network back to disk as OpenVINO IR, i.e., its topology to `xml_path` and its
+    /// weights to `bin_path`, e.g., to inspect it after [`CNNNetwork::reshape`]. OpenVINO's C API
+    /// has no serialization function, so this always returns [`InferenceError::NotImplemented`].
+    pub fn serialize(&self, xml_path: &str, bin_path: &str) -> Result<()> {
+        let _ = (xml_path, bin_path);
+        Err(InferenceError::NotImplemented)
+    }
+
     /// Retrieve the number of network inputs.
     pub fn get_inputs_len(&self) -> Result<usize> {
         let mut num: usize = 0;
@@ -71,6 +113,119 @@ impl CNNNetwork {
         Ok(rust_name)
     }
 
+    /// Retrieve the names of all input tensors, in declaration order.
+    pub fn get_input_names(&self) -> Result<Vec<String>> {
+        (0..self.get_inputs_len()?)
+            .map(|index| self.get_input_name(index))
+            .collect()
+    }
+
+    /// Retrieve the names of all output tensors, in declaration order.
+    pub fn get_output_names(&self) -> Result<Vec<String>> {
+        (0..self.get_outputs_len()?)
+            .map(|index| self.get_output_name(index))
+            .collect()
+    }
+
+    /// Change the dimensions of the network inputs named in `shapes`; inputs not present in
+    /// `shapes` keep their current dimensions. Each new shape must have the same rank as the
+    /// input's current shape, otherwise this returns [`InferenceError::ParameterMismatch`]. An
+    /// unknown input name returns [`InferenceError::NotFound`].
+    ///
+    /// [`InferenceError::ParameterMismatch`]: crate::InferenceError::ParameterMismatch
+    /// [`InferenceError::NotFound`]: crate::InferenceError::NotFound
+    pub fn reshape(&mut self, shapes: &HashMap<String, Vec<usize>>) -> Result<()> {
+        let mut input_shapes = input_shapes_t {
+            shapes: std::ptr::null_mut(),
+            shape_num: 0,
+        };
+        try_unsafe!(ie_network_get_input_shapes(
+            self.instance,
+            std::ptr::addr_of_mut!(input_shapes)
+        ))?;
+
+        let result = Self::update_shapes(&mut input_shapes, shapes)
+            .and_then(|()| try_unsafe!(ie_network_reshape(self.instance, input_shapes)));
+        unsafe { ie_network_input_shapes_free(std::ptr::addr_of_mut!(input_shapes)) };
+        result
+    }
+
+    /// Retrieve the batch size of the network, i.e., the first dimension of its inputs.
+    pub fn get_batch_size(&self) -> Result<usize> {
+        self.input_shapes()?
+            .into_iter()
+            .find_map(|(_, dims)| dims.first().copied())
+            .ok_or(InferenceError::NotFound)
+    }
+
+    /// Set the batch size of the network, i.e., the first dimension of all of its (non-scalar)
+    /// inputs. This is a constrained form of [`CNNNetwork::reshape`]: it replaces the first
+    /// dimension of each input shape, so any other dimensions previously set by `reshape` are
+    /// kept, and a later `reshape` may overwrite the batch size again.
+    pub fn set_batch_size(&mut self, size: usize) -> Result<()> {
+        let shapes = self
+            .input_shapes()?
+            .into_iter()
+            .filter(|(_, dims)| !dims.is_empty())
+            .map(|(name, mut dims)| {
+                dims[0] = size;
+                (name, dims)
+            })
+            .collect();
+        self.reshape(&shapes)
+    }
+
+    /// Retrieve the current dimensions of each input, in declaration order.
+    fn input_shapes(&self) -> Result<Vec<(String, Vec<usize>)>> {
+        let mut input_shapes = input_shapes_t {
+            shapes: std::ptr::null_mut(),
+            shape_num: 0,
+        };
+        try_unsafe!(ie_network_get_input_shapes(
+            self.instance,
+            std::ptr::addr_of_mut!(input_shapes)
+        ))?;
+        let shapes = if input_shapes.shapes.is_null() {
+            vec![]
+        } else {
+            unsafe { std::slice::from_raw_parts(input_shapes.shapes, input_shapes.shape_num) }
+                .iter()
+                .map(|input| {
+                    let name = unsafe { CStr::from_ptr(input.name) }
+                        .to_string_lossy()
+                        .into_owned();
+                    (name, input.shape.dims[..input.shape.ranks].to_vec())
+                })
+                .collect()
+        };
+        unsafe { ie_network_input_shapes_free(std::ptr::addr_of_mut!(input_shapes)) };
+        Ok(shapes)
+    }
+
+    /// Overwrite the dimensions in `input_shapes` (as retrieved from OpenVINO) with the
+    /// user-provided `shapes`.
+    fn update_shapes(
+        input_shapes: &mut input_shapes_t,
+        shapes: &HashMap<String, Vec<usize>>,
+    ) -> Result<()> {
+        let current = if input_shapes.shapes.is_null() {
+            &mut []
+        } else {
+            unsafe { std::slice::from_raw_parts_mut(input_shapes.shapes, input_shapes.shape_num) }
+        };
+        for (name, dims) in shapes {
+            let input = current
+                .iter_mut()
+                .find(|input| unsafe { CStr::from_ptr(input.name) }.to_bytes() == name.as_bytes())
+                .ok_or(InferenceError::NotFound)?;
+            if input.shape.ranks != dims.len() {
+                return Err(InferenceError::ParameterMismatch);
+            }
+            input.shape.dims[..dims.len()].copy_from_slice(dims);
+        }
+        Ok(())
+    }
+
     /// Configure a resize algorithm for the input tensor at `input_name`.
     pub fn set_input_resize_algorithm(
         &mut self,
@@ -84,6 +239,41 @@ impl CNNNetwork {
         ))
     }
 
+    /// Retrieve the resize algorithm configured for the input tensor at `input_name`.
+    pub fn get_input_resize_algorithm(&self, input_name: &str) -> Result<ResizeAlgorithm> {
+        let mut algorithm = MaybeUninit::uninit();
+        try_unsafe!(ie_network_get_input_resize_algorithm(
+            self.instance,
+            cstr!(input_name),
+            algorithm.as_mut_ptr()
+        ))?;
+        // Safety: the call succeeded so the value must be initialized.
+        Ok(unsafe { algorithm.assume_init() })
+    }
+
+    /// Configure the color format of the data passed to the input tensor at `input_name`, e.g.,
+    /// [`ColorFormat::NV12`] to pass blobs created with [`Blob::new_nv12`](crate::Blob::new_nv12).
+    /// OpenVINO converts the data to the color format expected by the network.
+    pub fn set_color_format(&mut self, input_name: &str, format: ColorFormat) -> Result<()> {
+        try_unsafe!(ie_network_set_color_format(
+            self.instance,
+            cstr!(input_name),
+            format
+        ))
+    }
+
+    /// Retrieve the color format configured for the input tensor at `input_name`.
+    pub fn get_color_format(&self, input_name: &str) -> Result<ColorFormat> {
+        let mut format = MaybeUninit::uninit();
+        try_unsafe!(ie_network_get_color_format(
+            self.instance,
+            cstr!(input_name),
+            format.as_mut_ptr()
+        ))?;
+        // Safety: the call succeeded so the value must be initialized.
+        Ok(unsafe { format.assume_init() })
+    }
+
     /// Configure a layout for the input tensor at `input_name`.
     pub fn set_input_layout(&mut self, input_name: &str, layout: Layout) -> Result<()> {
         try_unsafe!(ie_network_set_input_layout(
@@ -93,6 +283,73 @@ impl CNNNetwork {
         ))
     }
 
+    /// Configure the same `layout` for every input tensor; see [`CNNNetwork::set_input_layout`].
+    pub fn set_all_input_layouts(&mut self, layout: Layout) -> Result<()> {
+        for name in self.get_input_names()? {
+            self.set_input_layout(&name, layout)?;
+        }
+        Ok(())
+    }
+
+    /// Retrieve the dimensions of the input tensor at `input_name`.
+    pub fn get_input_dims(&self, input_name: &str) -> Result<Vec<usize>> {
+        let mut dims = MaybeUninit::uninit();
+        try_unsafe!(ie_network_get_input_dims(
+            self.instance,
+            cstr!(input_name),
+            dims.as_mut_ptr()
+        ))?;
+        // Safety: the call succeeded so the value must be initialized.
+        let dims = unsafe { dims.assume_init() };
+        Ok(dims.dims[..dims.ranks].to_vec())
+    }
+
+    /// Retrieve the dimensions of the output tensor at `output_name`.
+    pub fn get_output_dims(&self, output_name: &str) -> Result<Vec<usize>> {
+        let mut dims = MaybeUninit::uninit();
+        try_unsafe!(ie_network_get_output_dims(
+            self.instance,
+            cstr!(output_name),
+            dims.as_mut_ptr()
+        ))?;
+        // Safety: the call succeeded so the value must be initialized.
+        let dims = unsafe { dims.assume_init() };
+        Ok(dims.dims[..dims.ranks].to_vec())
+    }
+
+    /// Retrieve the layout of the input tensor at `input_name`.
+    pub fn get_input_layout(&self, input_name: &str) -> Result<Layout> {
+        let mut layout = MaybeUninit::uninit();
+        try_unsafe!(ie_network_get_input_layout(
+            self.instance,
+            cstr!(input_name),
+            layout.as_mut_ptr()
+        ))?;
+        // Safety: the call succeeded so the value must be initialized.
+        Ok(unsafe { layout.assume_init() })
+    }
+
+    /// Configure a layout for the output tensor at `output_name`.
+    pub fn set_output_layout(&mut self, output_name: &str, layout: Layout) -> Result<()> {
+        try_unsafe!(ie_network_set_output_layout(
+            self.instance,
+            cstr!(output_name),
+            layout
+        ))
+    }
+
+    /// Retrieve the layout of the output tensor at `output_name`.
+    pub fn get_output_layout(&self, output_name: &str) -> Result<Layout> {
+        let mut layout = MaybeUninit::uninit();
+        try_unsafe!(ie_network_get_output_layout(
+            self.instance,
+            cstr!(output_name),
+            layout.as_mut_ptr()
+        ))?;
+        // Safety: the call succeeded so the value must be initialized.
+        Ok(unsafe { layout.assume_init() })
+    }
+
     /// Configure the precision for the input tensor at `input_name`.
     pub fn set_input_precision(&mut self, input_name: &str, precision: Precision) -> Result<()> {
         try_unsafe!(ie_network_set_input_precision(
@@ -102,6 +359,39 @@ impl CNNNetwork {
         ))
     }
 
+    /// Configure the same `precision` for every input tensor; see
+    /// [`CNNNetwork::set_input_precision`].
+    pub fn set_all_input_precisions(&mut self, precision: Precision) -> Result<()> {
+        for name in self.get_input_names()? {
+            self.set_input_precision(&name, precision)?;
+        }
+        Ok(())
+    }
+
+    /// Retrieve the precision of the input tensor at `input_name`.
+    pub fn get_input_precision(&self, input_name: &str) -> Result<Precision> {
+        let mut precision = MaybeUninit::uninit();
+        try_unsafe!(ie_network_get_input_precision(
+            self.instance,
+            cstr!(input_name),
+            precision.as_mut_ptr()
+        ))?;
+        // Safety: the call succeeded so the value must be initialized.
+        Ok(unsafe { precision.assume_init() })
+    }
+
+    /// Retrieve the precision of the output tensor at `output_name`.
+    pub fn get_output_precision(&self, output_name: &str) -> Result<Precision> {
+        let mut precision = MaybeUninit::uninit();
+        try_unsafe!(ie_network_get_output_precision(
+            self.instance,
+            cstr!(output_name),
+            precision.as_mut_ptr()
+        ))?;
+        // Safety: the call succeeded so the value must be initialized.
+        Ok(unsafe { precision.assume_init() })
+    }
+
     /// Configure the precision for the output tensor at `output_name`.
     pub fn set_output_precision(&mut self, output_name: &str, precision: Precision) -> Result<()> {
         try_unsafe!(ie_network_set_output_precision(
@@ -112,23 +402,237 @@ impl CNNNetwork {
     }
 }
 
+/// The input and output names of a network, in index order, and their tensor descriptions. The
+/// default value is empty and unrecorded, as for imported networks.
+#[derive(Debug, Default)]
+pub(crate) struct IoNames {
+    pub(crate) inputs: Vec<String>,
+    pub(crate) outputs: Vec<String>,
+    input_descs: HashMap<String, TensorDesc>,
+    output_descs: HashMap<String, TensorDesc>,
+    recorded: bool,
+}
+
+impl IoNames {
+    /// Record the input and output names of `network`, along with the tensor descriptions its
+    /// compiled blobs will have (i.e., the network's current layouts, dimensions and precisions).
+    pub(crate) fn of(network: &CNNNetwork) -> Result<Self> {
+        let inputs = network.get_input_names()?;
+        let outputs = network.get_output_names()?;
+        let input_descs = inputs
+            .iter()
+            .map(|name| {
+                let desc = TensorDesc::new(
+                    network.get_input_layout(name)?,
+                    &network.get_input_dims(name)?,
+                    network.get_input_precision(name)?,
+                );
+                Ok((name.clone(), desc))
+            })
+            .collect::<Result<_>>()?;
+        let output_descs = outputs
+            .iter()
+            .map(|name| {
+                let desc = TensorDesc::new(
+                    network.get_output_layout(name)?,
+                    &network.get_output_dims(name)?,
+                    network.get_output_precision(name)?,
+                );
+                Ok((name.clone(), desc))
+            })
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            inputs,
+            outputs,
+            input_descs,
+            output_descs,
+            recorded: true,
+        })
+    }
+
+    /// Return these names, or [`InferenceError::NotImplemented`] if they were never recorded.
+    pub(crate) fn recorded(&self) -> Result<&Self> {
+        if self.recorded {
+            Ok(self)
+        } else {
+            Err(InferenceError::NotImplemented)
+        }
+    }
+}
+
+/// Copy a recorded tensor description, or return [`InferenceError::NotFound`] if there is none.
+fn copy_desc(desc: Option<&TensorDesc>) -> Result<TensorDesc> {
+    let desc = desc.ok_or(InferenceError::NotFound)?;
+    Ok(TensorDesc {
+        instance: desc.instance,
+    })
+}
+
 /// See
 /// [`ExecutableNetwork`](https://docs.openvinotoolkit.org/latest/classInferenceEngine_1_1ExecutableNetwork.html).
 pub struct ExecutableNetwork {
     pub(crate) instance: *mut ie_executable_network_t,
+    // OpenVINO's C API cannot list the inputs and outputs of a compiled network, so these are
+    // recorded from the original `CNNNetwork` (if any) for index-based blob access.
+    pub(crate) names: Arc<IoNames>,
+    // The weights memory of the original `CNNNetwork`, which the compiled network may refer to.
+    pub(crate) memory: KeepAlive,
+    // The largest batch size accepted by `InferRequest::set_batch_size`, if the network was
+    // loaded with dynamic batching enabled; see `max_dynamic_batch_size`.
+    pub(crate) max_batch_size: Option<usize>,
 }
 drop_using_function!(ExecutableNetwork, ie_exec_network_free);
 
+/// Determine the largest batch size accepted by [`InferRequest::set_batch_size`] for a network
+/// loaded with `config`, or `None` if it does not enable dynamic batching. A missing or negative
+/// `DYN_BATCH_LIMIT` means the network's own batch size is the only limit.
+pub(crate) fn max_dynamic_batch_size(config: &HashMap<String, String>) -> Option<usize> {
+    if config.get(keys::DYN_BATCH_ENABLED)? != "YES" {
+        return None;
+    }
+    let limit = config.get(keys::DYN_BATCH_LIMIT);
+    Some(
+        limit
+            .and_then(|limit| limit.parse().ok())
+            .unwrap_or(usize::MAX),
+    )
+}
+
 unsafe impl Send for ExecutableNetwork {}
+// OpenVINO allows creating inference requests from the same executable network concurrently.
+unsafe impl Sync for ExecutableNetwork {}
 
 impl ExecutableNetwork {
-    /// Create an [`InferRequest`].
-    pub fn create_infer_request(&mut self) -> Result<InferRequest> {
+    /// Create an [`InferRequest`]; multiple requests may be created from the same
+    /// [`ExecutableNetwork`].
+    pub fn create_infer_request(&self) -> Result<InferRequest> {
         let mut instance = std::ptr::null_mut();
         try_unsafe!(ie_exec_network_create_infer_request(
             self.instance,
             std::ptr::addr_of_mut!(instance)
         ))?;
-        Ok(InferRequest { instance })
+        Ok(unsafe {
+            InferRequest::from_raw_pointer(
+                instance,
+                self.max_batch_size,
+                self.names.clone(),
+                self.memory.clone(),
+            )
+        })
+    }
+
+    /// Retrieve the names of all input tensors, in declaration order, as recorded from the
+    /// [`CNNNetwork`] this was loaded from. Imported networks (see
+    /// [`Core::import_network`](crate::Core::import_network)) do not know their input names, so
+    /// this returns [`InferenceError::NotImplemented`] for them.
+    pub fn get_input_names(&self) -> Result<Vec<String>> {
+        Ok(self.names.recorded()?.inputs.clone())
+    }
+
+    /// Retrieve the names of all output tensors, in declaration order; see
+    /// [`ExecutableNetwork::get_input_names`].
+    pub fn get_output_names(&self) -> Result<Vec<String>> {
+        Ok(self.names.recorded()?.outputs.clone())
+    }
+
+    /// Retrieve the tensor description of the input `name` as compiled, i.e., as the
+    /// [`CNNNetwork`] was configured when loaded (e.g., after [`CNNNetwork::reshape`]). OpenVINO's
+    /// C API has no accessors for the executable network's inputs, so these descriptions are
+    /// recorded at load time; an unknown `name` returns [`InferenceError::NotFound`]. Networks
+    /// without recorded names (e.g., imported with
+    /// [`Core::import_network`](crate::Core::import_network)) instead create a temporary
+    /// [`InferRequest`] and inspect the blob it allocated for `name`.
+    pub fn get_input_info(&self, name: &str) -> Result<TensorDesc> {
+        match self.names.recorded() {
+            Ok(names) => copy_desc(names.input_descs.get(name)),
+            Err(_) => self.blob_desc(name),
+        }
+    }
+
+    /// Retrieve the tensor description of the output `name` as compiled; see
+    /// [`ExecutableNetwork::get_input_info`].
+    pub fn get_output_info(&self, name: &str) -> Result<TensorDesc> {
+        match self.names.recorded() {
+            Ok(names) => copy_desc(names.output_descs.get(name)),
+            Err(_) => self.blob_desc(name),
+        }
+    }
+
+    fn blob_desc(&self, name: &str) -> Result<TensorDesc> {
+        self.create_infer_request()?.get_blob(name)?.tensor_desc()
+    }
+
+    /// Retrieve the value of the read-only `metric` (e.g., `NETWORK_NAME` or
+    /// `OPTIMAL_NUMBER_OF_INFER_REQUESTS`) for this compiled network, converted to a string as in
+    /// [`Core::get_metric`](crate::Core::get_metric).
+    pub fn get_metric(&self, metric: &str) -> Result<String> {
+        let kind = MetricKind::of(metric)?;
+        let mut param = empty_param();
+        try_unsafe!(ie_exec_network_get_metric(
+            self.instance,
+            cstr!(metric),
+            std::ptr::addr_of_mut!(param)
+        ))?;
+        Ok(unsafe { take_metric_param(kind, &mut param) })
+    }
+
+    /// Retrieve the number of inference requests this network should use to fully utilize its
+    /// device, i.e., its `OPTIMAL_NUMBER_OF_INFER_REQUESTS` metric. This is a good size for an
+    /// [`InferPool`](crate::InferPool).
+    pub fn optimal_number_of_infer_requests(&self) -> Result<usize> {
+        // The metric is a `MetricKind::Number`, which `get_metric` formats as a decimal integer.
+        self.get_metric(keys::OPTIMAL_NUMBER_OF_INFER_REQUESTS)?
+            .parse()
+            .map_err(|_| InferenceError::Unexpected)
+    }
+
+    /// Retrieve the number of parallel inference streams this network was compiled with, if its
+    /// device reports one (e.g., through `NUM_STREAMS` or `CPU_THROUGHPUT_STREAMS`); devices
+    /// without streams, or configured to choose them automatically, return `None`.
+    pub fn number_of_streams(&self) -> Option<usize> {
+        [
+            keys::NUM_STREAMS,
+            keys::CPU_THROUGHPUT_STREAMS,
+            keys::GPU_THROUGHPUT_STREAMS,
+        ]
+        .iter()
+        .find_map(|key| self.get_config(key).ok()?.parse().ok())
+    }
+
+    /// Retrieve the value of the configuration `key` this network was compiled with.
+    pub fn get_config(&self, key: &str) -> Result<String> {
+        let mut param = empty_param();
+        try_unsafe!(ie_exec_network_get_config(
+            self.instance,
+            cstr!(key),
+            std::ptr::addr_of_mut!(param)
+        ))?;
+        // Safety: OpenVINO returns all configuration values as strings.
+        Ok(unsafe { take_string_param(&mut param) })
+    }
+
+    /// Write the compiled network to the file at `path`; it can later be loaded with
+    /// [`Core::import_network`](crate::Core::import_network) on the same kind of device.
+    pub fn export(&self, path: &str) -> Result<()> {
+        try_unsafe!(ie_core_export_network(self.instance, cstr!(path)))
+    }
+
+    /// Serialize the compiled network into a vector of bytes; it can later be loaded with
+    /// [`Core::import_network_from_buffer`](crate::Core::import_network_from_buffer) on the same
+    /// kind of device, e.g., to cache compiled networks somewhere other than the local file system.
+    /// The C API only exports networks to files, so this exports to a file in a private temporary
+    /// directory, which is removed once the file has been read; this still requires a writable
+    /// temporary directory (see [`std::env::temp_dir`]).
+    pub fn export_to_vec(&self) -> std::result::Result<Vec<u8>, SetupError> {
+        let dir = TemporaryDir::new().map_err(SetupError::Export)?;
+        let path = dir.join("network.blob");
+        // The directory is new and private, so nothing else should have created this file; never
+        // export over (or read back) a file that is already there.
+        if path.exists() {
+            return Err(SetupError::Export(io::ErrorKind::AlreadyExists.into()));
+        }
+        let path_str = path.to_str().ok_or(LoadingError::CannotStringifyPath)?;
+        self.export(path_str)?;
+        fs::read(&path).map_err(SetupError::Export)
     }
 }
@@ -0,0 +1,132 @@
+//! Distribute inferences across a pool of [`InferRequest`]s, each driven by its own thread.
+
+use crate::network::ExecutableNetwork;
+use crate::request::{InferRequest, Outputs};
+use crate::{util::Result, Blob, BlobError, InferenceError};
+use std::collections::HashMap;
+use std::sync::mpsc::{self, Receiver, Sender};
+use std::sync::{Arc, Mutex};
+use std::thread::{self, JoinHandle};
+
+/// Own an [`ExecutableNetwork`] and a fixed number of [`InferRequest`]s created from it. Each
+/// request is driven by a worker thread; submitted inferences are queued and picked up by
+/// whichever worker is idle first. [`InferPool`] is `Sync`, so a single pool can be shared (e.g.,
+/// in an `Arc`) by any number of submitting threads.
+///
+/// Output blobs are copied out of the request before they are returned, since the request reuses
+/// its output memory for the next inference.
+pub struct InferPool {
+    // Closing this channel (see `Drop`) stops the workers.
+    jobs: Sender<Job>,
+    workers: Vec<JoinHandle<()>>,
+    // The requests are driven by the workers, but the network they were created from must outlive
+    // them; `Drop` joins the workers before this field is dropped.
+    _network: ExecutableNetwork,
+}
+
+impl InferPool {
+    /// Create a pool of `num_requests` inference requests on `network`. A good value for
+    /// `num_requests` is the device's `OPTIMAL_NUMBER_OF_INFER_REQUESTS` metric; `0` returns
+    /// [`InferenceError::OutOfBounds`].
+    pub fn new(network: ExecutableNetwork, num_requests: usize) -> Result<Self> {
+        if num_requests == 0 {
+            return Err(InferenceError::OutOfBounds);
+        }
+        let (sender, receiver) = mpsc::channel();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let mut workers = Vec::with_capacity(num_requests);
+        for _ in 0..num_requests {
+            let request = network.create_infer_request()?;
+            let receiver = receiver.clone();
+            workers.push(thread::spawn(move || work(request, &receiver)));
+        }
+        Ok(Self {
+            jobs: sender,
+            workers,
+            _network: network,
+        })
+    }
+
+    /// Return the number of inference requests in the pool.
+    pub fn size(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// Queue an inference that sets each of the `inputs` blobs and, once complete, copies out the
+    /// blobs for each of the `outputs` names. The result is sent to the returned [`Receiver`]; if
+    /// the worker running the inference panics, the [`Receiver`] is disconnected instead.
+    pub fn submit(
+        &self,
+        inputs: HashMap<String, Blob>,
+        outputs: &[&str],
+    ) -> Receiver<std::result::Result<Outputs, BlobError>> {
+        let (reply, result) = mpsc::channel();
+        let job = Job {
+            inputs,
+            outputs: outputs.iter().map(ToString::to_string).collect(),
+            reply,
+        };
+        // Sending only fails if every worker has panicked, which disconnects `result` as well.
+        let _ = self.jobs.send(job);
+        result
+    }
+
+    /// Run an inference (see [`InferPool::submit`]) and wait for its result. If the worker running
+    /// the inference panics, this returns [`InferenceError::Unexpected`].
+    pub fn infer(
+        &self,
+        inputs: HashMap<String, Blob>,
+        outputs: &[&str],
+    ) -> std::result::Result<Outputs, BlobError> {
+        self.submit(inputs, outputs)
+            .recv()
+            .map_err(|_| InferenceError::Unexpected)?
+    }
+}
+
+impl Drop for InferPool {
+    fn drop(&mut self) {
+        // Replacing the sender with a disconnected one closes the job channel.
+        self.jobs = mpsc::channel().0;
+        for worker in self.workers.drain(..) {
+            // A worker only panics if OpenVINO itself does; there is nothing left to clean up.
+            let _ = worker.join();
+        }
+    }
+}
+
+/// A queued inference and the channel to send its result to.
+struct Job {
+    inputs: HashMap<String, Blob>,
+    outputs: Vec<String>,
+    reply: Sender<std::result::Result<Outputs, BlobError>>,
+}
+
+impl Job {
+    fn run(&self, request: &mut InferRequest) -> std::result::Result<Outputs, BlobError> {
+        for (name, blob) in &self.inputs {
+            request.set_blob(name, blob)?;
+        }
+        request.infer()?;
+        self.outputs
+            .iter()
+            .map(|name| Ok((name.clone(), request.get_blob(name)?.deep_copy()?)))
+            .collect()
+    }
+}
+
+/// Run jobs on `request` until the pool closes the job channel.
+fn work(mut request: InferRequest, jobs: &Mutex<Receiver<Job>>) {
+    loop {
+        // Only hold the lock while waiting for a job so that other workers can run concurrently.
+        let job = jobs.lock().expect("no worker panics while waiting").recv();
+        match job {
+            Ok(job) => {
+                let result = job.run(&mut request);
+                // The submitter may have stopped waiting for the result, which is fine.
+                let _ = job.reply.send(result);
+            }
+            Err(_) => break,
+        }
+    }
+}
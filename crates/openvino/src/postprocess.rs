@@ -0,0 +1,114 @@
+//! Post-process inference outputs, e.g., to read a classification map out of an output [`Blob`].
+//! These helpers check the [`Blob`]'s layout and precision before interpreting its contents.
+
+use crate::{Blob, BlobError, Layout, PrecisionType};
+
+/// Copy the contents of `blob` into an owned vector of `T`; `T` must match the blob's precision
+/// (see [`Blob::as_slice`]). Unlike the slice, the vector remains valid after the blob is dropped
+/// or overwritten by the next inference.
+pub fn blob_to_vec<T: PrecisionType>(blob: &Blob) -> Result<Vec<T>, BlobError> {
+    Ok(blob.as_slice::<T>()?.to_vec())
+}
+
+/// Compute, for each pixel of an `NCHW` `FP32` class-probability map (e.g., the output of a
+/// segmentation network), the index of the channel with the highest value. The result contains
+/// `N * H * W` class indices in `NHW` order; ties resolve to the lowest channel index.
+///
+/// Returns [`BlobError::LayoutMismatch`] if `blob` is not `NCHW` and
+/// [`BlobError::PrecisionMismatch`] if it is not `FP32`.
+pub fn blob_argmax_channel(blob: &Blob) -> Result<Vec<u32>, BlobError> {
+    let desc = blob.tensor_desc()?;
+    if desc.layout() != Layout::NCHW || desc.dims().len() != 4 {
+        return Err(BlobError::LayoutMismatch {
+            expected: Layout::NCHW,
+            actual: desc.layout(),
+        });
+    }
+    let (batch, channels, height, width) = (
+        desc.dims()[0],
+        desc.dims()[1],
+        desc.dims()[2],
+        desc.dims()[3],
+    );
+    let data = blob.as_slice::<f32>()?;
+    let plane = height * width;
+
+    let mut classes = Vec::with_capacity(batch * plane);
+    for image in data.chunks_exact(channels * plane).take(batch) {
+        for pixel in 0..plane {
+            let mut best = 0;
+            for channel in 1..channels {
+                if image[channel * plane + pixel] > image[best * plane + pixel] {
+                    best = channel;
+                }
+            }
+            // A channel index always fits: no network has 2^32 output classes.
+            #[allow(clippy::cast_possible_truncation)]
+            classes.push(best as u32);
+        }
+    }
+    Ok(classes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Precision, TensorDesc};
+
+    fn f32_blob(layout: Layout, dims: &[usize], values: &[f32]) -> Blob {
+        let desc = TensorDesc::new(layout, dims, Precision::FP32);
+        let mut blob = Blob::allocate(&desc).unwrap();
+        blob.as_mut_slice::<f32>().unwrap().copy_from_slice(values);
+        blob
+    }
+
+    #[test]
+    fn argmax_channel() {
+        openvino_sys::library::load().expect("unable to find an OpenVINO shared library");
+
+        // Two images with three classes over a 1x2 map; the last pixel ties between classes 0 and 1.
+        #[rustfmt::skip]
+        let values = [
+            0.1, 0.7,   0.8, 0.2,   0.1, 0.1,
+            0.0, 0.3,   0.5, 0.3,   0.9, 0.1,
+        ];
+        let blob = f32_blob(Layout::NCHW, &[2, 3, 1, 2], &values);
+        assert_eq!(blob_argmax_channel(&blob), Ok(vec![1, 0, 2, 0]));
+    }
+
+    #[test]
+    fn argmax_channel_mismatch() {
+        openvino_sys::library::load().expect("unable to find an OpenVINO shared library");
+
+        let blob = f32_blob(Layout::NHWC, &[1, 2, 1, 1], &[0.0, 1.0]);
+        assert_eq!(
+            blob_argmax_channel(&blob),
+            Err(BlobError::LayoutMismatch {
+                expected: Layout::NCHW,
+                actual: Layout::NHWC
+            })
+        );
+
+        let desc = TensorDesc::new(Layout::NCHW, &[1, 2, 1, 1], Precision::U8);
+        let blob = Blob::new(&desc, &[0, 1]).unwrap();
+        assert_eq!(
+            blob_argmax_channel(&blob),
+            Err(BlobError::PrecisionMismatch {
+                expected: Precision::FP32,
+                actual: Precision::U8
+            })
+        );
+    }
+
+    #[test]
+    fn to_vec() {
+        openvino_sys::library::load().expect("unable to find an OpenVINO shared library");
+
+        let blob = f32_blob(Layout::NC, &[1, 3], &[1.0, 2.0, 3.0]);
+        assert_eq!(blob_to_vec::<f32>(&blob), Ok(vec![1.0, 2.0, 3.0]));
+        assert!(matches!(
+            blob_to_vec::<u8>(&blob),
+            Err(BlobError::PrecisionMismatch { .. })
+        ));
+    }
+}
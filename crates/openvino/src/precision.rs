@@ -0,0 +1,59 @@
+//! Link the OpenVINO [`Precision`]s to their equivalent Rust types.
+
+use crate::Precision;
+
+/// Associate a Rust type with the OpenVINO [`Precision`] of the same element type; this allows
+/// [`Blob`](crate::Blob) to check typed accesses of its contents (e.g., `f32` is
+/// [`Precision::FP32`]).
+///
+/// # Safety
+///
+/// Implementors must be plain-old-data types for which any bit pattern of `size_of::<Self>()`
+/// bytes is valid, since blob contents are reinterpreted as `Self` without any further checks.
+pub unsafe trait PrecisionType: Copy {
+    /// The OpenVINO precision equivalent to this type.
+    const PRECISION: Precision;
+}
+
+macro_rules! precision_type {
+    ($ty: ty, $precision: expr) => {
+        unsafe impl PrecisionType for $ty {
+            const PRECISION: Precision = $precision;
+        }
+    };
+}
+
+precision_type!(f32, Precision::FP32);
+precision_type!(f64, Precision::FP64);
+precision_type!(u8, Precision::U8);
+precision_type!(i8, Precision::I8);
+precision_type!(u16, Precision::U16);
+precision_type!(i16, Precision::I16);
+precision_type!(u32, Precision::U32);
+precision_type!(i32, Precision::I32);
+precision_type!(u64, Precision::U64);
+precision_type!(i64, Precision::I64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn element_size_matches<T: PrecisionType>() {
+        assert_eq!(T::PRECISION.size_in_bytes(), std::mem::size_of::<T>());
+    }
+
+    #[test]
+    fn element_sizes() {
+        element_size_matches::<f32>();
+        element_size_matches::<f64>();
+        element_size_matches::<u8>();
+        element_size_matches::<i8>();
+        element_size_matches::<u16>();
+        element_size_matches::<i16>();
+        element_size_matches::<u32>();
+        element_size_matches::<i32>();
+        element_size_matches::<u64>();
+        element_size_matches::<i64>();
+        assert_eq!(Precision::FP16.size_in_bytes(), 2);
+    }
+}
@@ -0,0 +1,11 @@
+//! Re-export the types used by most programs, so that a single glob import covers them:
+//!
+//! ```
+//! use openvino::prelude::*;
+//! let desc = TensorDesc::new(Layout::NHWC, &[1, 3, 227, 227], Precision::FP32);
+//! ```
+
+pub use crate::{
+    Blob, CNNNetwork, Core, ExecutableNetwork, InferRequest, Layout, Precision, PrecisionType,
+    TensorDesc,
+};
@@ -1,24 +1,81 @@
-use crate::blob::Blob;
-use crate::{cstr, drop_using_function, try_unsafe, util::Result};
+use crate::blob::{Blob, KeepAlive};
+use crate::network::IoNames;
+use crate::{cstr, drop_using_function, try_unsafe, util::Result, BlobError, InferenceError};
 use openvino_sys::{
-    ie_infer_request_free, ie_infer_request_get_blob, ie_infer_request_infer,
-    ie_infer_request_set_batch, ie_infer_request_set_blob, ie_infer_request_t,
+    ie_complete_call_back_t, ie_infer_request_free, ie_infer_request_get_blob,
+    ie_infer_request_infer, ie_infer_request_infer_async, ie_infer_request_set_batch,
+    ie_infer_request_set_blob, ie_infer_request_t, ie_infer_request_wait,
+    ie_infer_set_completion_callback,
 };
+use std::collections::HashMap;
+use std::os::raw::c_void;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+use std::sync::Arc;
 
 /// See
 /// [`InferRequest`](https://docs.openvinotoolkit.org/latest/classInferenceEngine_1_1InferRequest.html).
 pub struct InferRequest {
     pub(crate) instance: *mut ie_infer_request_t,
+    // OpenVINO keeps a pointer to the registered callback, so it is boxed (i.e., never moves) and
+    // only replaced while no inference is running. See `InferRequest::set_completion_callback`.
+    callback: Option<Box<CompletionCallback>>,
+    // The largest batch size accepted by `InferRequest::set_batch_size`, if the network was
+    // compiled with dynamic batching enabled.
+    max_batch_size: Option<usize>,
+    names: Arc<IoNames>,
+    // The Rust-owned memory OpenVINO may refer to: that of the network, and that of each blob
+    // assigned to the request by name (see `Blob::from_vec`). It is dropped after `instance`.
+    _network_memory: KeepAlive,
+    blob_memory: HashMap<String, KeepAlive>,
 }
 drop_using_function!(InferRequest, ie_infer_request_free);
 
+/// The named output blobs of a completed inference.
+pub type Outputs = HashMap<String, Blob>;
+
 unsafe impl Send for InferRequest {}
 unsafe impl Sync for InferRequest {}
 
 impl InferRequest {
-    /// Set the batch size of the inference requests.
+    /// Passing this `timeout_ms` to [`InferRequest::wait`] blocks until the inference completes.
+    pub const WAIT_INFINITE: i64 = -1;
+
+    /// Passing this `timeout_ms` to [`InferRequest::wait`] checks the inference status without
+    /// blocking.
+    pub const WAIT_POLL: i64 = 0;
+
+    /// Construct an [`InferRequest`] from its associated pointer; `max_batch_size` is the
+    /// dynamic batching limit of its network, if enabled, `names` are its network's input and
+    /// output names and `network_memory` is the Rust-owned memory its network refers to.
+    pub(crate) unsafe fn from_raw_pointer(
+        instance: *mut ie_infer_request_t,
+        max_batch_size: Option<usize>,
+        names: Arc<IoNames>,
+        network_memory: KeepAlive,
+    ) -> Self {
+        Self {
+            instance,
+            callback: None,
+            max_batch_size,
+            names,
+            _network_memory: network_memory,
+            blob_memory: HashMap::new(),
+        }
+    }
+
+    /// Set the batch size of the inference requests. This only applies to networks loaded with
+    /// dynamic batching enabled in their load configuration (e.g., with
+    /// [`Core::load_network_dyn_batch`](crate::Core::load_network_dyn_batch)), where `size` may be
+    /// anything from `1` up to the network's batch size; inference then only computes the first
+    /// `size` batch entries. Without dynamic batching, this returns
+    /// [`InferenceError::NotImplemented`]; a `size` outside the dynamic batching limits returns
+    /// [`InferenceError::OutOfBounds`].
     pub fn set_batch_size(&mut self, size: usize) -> Result<()> {
-        try_unsafe!(ie_infer_request_set_batch(self.instance, size))
+        match self.max_batch_size {
+            None => Err(InferenceError::NotImplemented),
+            Some(max) if size == 0 || size > max => Err(InferenceError::OutOfBounds),
+            Some(_) => try_unsafe!(ie_infer_request_set_batch(self.instance, size)),
+        }
     }
 
     /// Assign a [Blob] to the input (i.e. `name`) on the network.
@@ -27,22 +84,215 @@ impl InferRequest {
             self.instance,
             cstr!(name),
             blob.instance
-        ))
+        ))?;
+        // The request now refers to the blob's memory, and no longer to that of the blob it
+        // replaced.
+        if blob.memory.is_empty() {
+            self.blob_memory.remove(name);
+        } else {
+            self.blob_memory
+                .insert(name.to_string(), blob.memory.clone());
+        }
+        Ok(())
+    }
+
+    /// Assign a [Blob] to the input `name` like [`InferRequest::set_blob`], but first check that
+    /// the blob's [`TensorDesc`](crate::TensorDesc) (layout, dimensions and precision) matches the
+    /// one the network expects for that input (i.e., that of the blob currently assigned to it),
+    /// returning [`BlobError::DescMismatch`] instead of OpenVINO's less specific
+    /// [`InferenceError::ParameterMismatch`]. Inputs expecting a different blob, e.g., an NV12
+    /// blob for a network with color-conversion pre-processing, must use
+    /// [`InferRequest::set_blob`].
+    pub fn set_blob_checked(
+        &mut self,
+        name: &str,
+        blob: &Blob,
+    ) -> std::result::Result<(), BlobError> {
+        let expected = self.get_blob(name)?.tensor_desc()?;
+        let actual = blob.tensor_desc()?;
+        if expected != actual {
+            return Err(BlobError::DescMismatch {
+                expected: Box::new(expected),
+                actual: Box::new(actual),
+            });
+        }
+        Ok(self.set_blob(name, blob)?)
     }
 
-    /// Retrieve a [Blob] from the output (i.e. `name`) on the network.
-    pub fn get_blob(&mut self, name: &str) -> Result<Blob> {
+    /// Retrieve the [Blob] of the input or output `name` on the network. The returned [Blob] shares
+    /// the request's memory rather than copying it, so a steady-state loop can avoid allocating
+    /// blob buffers altogether: retrieve (or set) each input blob once, then repeatedly overwrite
+    /// its contents (e.g., with [`Blob::as_mut_slice`]), call [`InferRequest::infer`] and read the
+    /// outputs. Output blobs are overwritten by the next inference; copy them with
+    /// [`Blob::deep_copy`] to keep them.
+    pub fn get_blob(&self, name: &str) -> Result<Blob> {
         let mut instance = std::ptr::null_mut();
         try_unsafe!(ie_infer_request_get_blob(
             self.instance,
             cstr!(name),
             std::ptr::addr_of_mut!(instance)
         ))?;
-        Ok(unsafe { Blob::from_raw_pointer(instance) })
+        let memory = self.blob_memory.get(name).cloned().unwrap_or_default();
+        Ok(unsafe { Blob::from_raw_pointer(instance, memory) })
+    }
+
+    /// Assign a [Blob] to the input at `index` on the network (in the order of
+    /// [`CNNNetwork::get_input_names`](crate::CNNNetwork::get_input_names)), e.g., `0` for a
+    /// single-input network. An `index` without an input returns [`InferenceError::OutOfBounds`];
+    /// this includes all indices of imported networks (see
+    /// [`Core::import_network`](crate::Core::import_network)), which do not record their names.
+    pub fn set_input_blob(&mut self, index: usize, blob: &Blob) -> Result<()> {
+        let names = self.names.clone();
+        let name = names.inputs.get(index).ok_or(InferenceError::OutOfBounds)?;
+        self.set_blob(name, blob)
+    }
+
+    /// Retrieve the [Blob] of the output at `index` on the network (in the order of
+    /// [`CNNNetwork::get_output_names`](crate::CNNNetwork::get_output_names)); see
+    /// [`InferRequest::set_input_blob`] and [`InferRequest::get_blob`].
+    pub fn get_output_blob(&self, index: usize) -> Result<Blob> {
+        let name = self
+            .names
+            .outputs
+            .get(index)
+            .ok_or(InferenceError::OutOfBounds)?;
+        self.get_blob(name)
+    }
+
+    /// Retrieve the [Blob]s of all the network's outputs, keyed by output name, e.g., after
+    /// [`InferRequest::infer`] on a network with several outputs. As with
+    /// [`InferRequest::get_blob`], the blobs share the request's memory and are overwritten by the
+    /// next inference; copy them with [`Blob::deep_copy`] to keep them. Requests created from an
+    /// imported network (see [`Core::import_network`](crate::Core::import_network)) do not know
+    /// their output names, so this returns [`InferenceError::NotImplemented`] for them.
+    pub fn get_all_outputs(&self) -> Result<Outputs> {
+        self.names
+            .recorded()?
+            .outputs
+            .iter()
+            .map(|name| Ok((name.clone(), self.get_blob(name)?)))
+            .collect()
+    }
+
+    /// Detach every blob previously assigned with [`InferRequest::set_blob`] (or its variants),
+    /// replacing each input and output blob with a newly-allocated, OpenVINO-owned blob of the same
+    /// tensor description. Afterwards, the request no longer refers to any caller's memory, though
+    /// the new blobs' contents are uninitialized: set or fill the inputs before the next
+    /// [`InferRequest::infer`]. Blobs retrieved earlier with [`InferRequest::get_blob`] keep
+    /// referring to the old memory. Requests created from an imported network (see
+    /// [`Core::import_network`](crate::Core::import_network)) do not know their blob names, so
+    /// there is nothing for them to reset.
+    pub fn reset(&mut self) -> Result<()> {
+        let names = self.names.clone();
+        for name in names.inputs.iter().chain(&names.outputs) {
+            let desc = self.get_blob(name)?.tensor_desc()?;
+            self.set_blob(name, &Blob::allocate(&desc)?)?;
+        }
+        Ok(())
     }
 
     /// Execute the inference request.
     pub fn infer(&mut self) -> Result<()> {
         try_unsafe!(ie_infer_request_infer(self.instance))
     }
+
+    /// Start the inference request without blocking; use [`InferRequest::wait`] to retrieve its
+    /// status.
+    pub fn infer_async(&mut self) -> Result<()> {
+        try_unsafe!(ie_infer_request_infer_async(self.instance))
+    }
+
+    /// Wait for the result of an inference started with [`InferRequest::infer_async`]. A negative
+    /// `timeout_ms` (e.g., [`InferRequest::WAIT_INFINITE`]) blocks until the result is ready, `0`
+    /// (i.e., [`InferRequest::WAIT_POLL`]) only checks the current status, and any other value
+    /// blocks for at most that many milliseconds.
+    pub fn wait(&self, timeout_ms: i64) -> Result<WaitStatus> {
+        match try_unsafe!(ie_infer_request_wait(self.instance, timeout_ms)) {
+            Ok(()) => Ok(WaitStatus::Ok),
+            Err(InferenceError::ResultNotReady) if timeout_ms == Self::WAIT_POLL => {
+                Ok(WaitStatus::Running)
+            }
+            Err(InferenceError::ResultNotReady) => Ok(WaitStatus::Timeout),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Execute the inference request, waiting at most `timeout_ms` milliseconds for it to complete;
+    /// an inference that takes longer returns [`InferenceError::TimedOut`].
+    ///
+    /// The OpenVINO C API cannot cancel an inference, so after a timeout the inference keeps
+    /// running in the background and the request remains busy: starting another inference fails
+    /// with [`InferenceError::RequestBusy`] until it completes. The request is reusable again once
+    /// [`InferRequest::wait`] (or [`InferRequest::is_ready`]) reports the inference as completed.
+    pub fn infer_timeout(&mut self, timeout_ms: i64) -> Result<()> {
+        self.infer_async()?;
+        match self.wait(timeout_ms)? {
+            WaitStatus::Ok => Ok(()),
+            WaitStatus::Timeout | WaitStatus::Running => Err(InferenceError::TimedOut),
+        }
+    }
+
+    /// Check, without blocking, whether an inference started with [`InferRequest::infer_async`]
+    /// has completed; an inference still running is not an error.
+    pub fn is_ready(&self) -> Result<bool> {
+        Ok(self.wait(Self::WAIT_POLL)? == WaitStatus::Ok)
+    }
+
+    /// Register a `callback` to run each time an inference started with
+    /// [`InferRequest::infer_async`] completes.
+    ///
+    /// The callback is invoked on an OpenVINO worker thread (hence the `Send` bound), not the
+    /// thread which started the inference. Any panic inside the callback is caught and discarded so
+    /// that it never unwinds across the C boundary. Registering a new callback replaces (and frees)
+    /// the previous one; since OpenVINO may still be invoking the previous callback while an
+    /// inference is running, this returns [`InferenceError::RequestBusy`] until the inference has
+    /// completed (see [`InferRequest::wait`]).
+    pub fn set_completion_callback<F>(&mut self, callback: F) -> Result<()>
+    where
+        F: FnMut() + Send + 'static,
+    {
+        if self.callback.is_some() && self.wait(Self::WAIT_POLL) == Ok(WaitStatus::Running) {
+            return Err(InferenceError::RequestBusy);
+        }
+        let mut callback = Box::new(CompletionCallback {
+            ffi: ie_complete_call_back_t {
+                completeCallBackFunc: Some(CompletionCallback::trampoline),
+                args: std::ptr::null_mut(),
+            },
+            closure: Box::new(callback),
+        });
+        // The boxed callback does not move, so it can safely refer to itself.
+        callback.ffi.args = std::ptr::addr_of_mut!(*callback).cast::<c_void>();
+        try_unsafe!(ie_infer_set_completion_callback(
+            self.instance,
+            std::ptr::addr_of_mut!(callback.ffi)
+        ))?;
+        // OpenVINO now refers to the new callback only, so the previous one can be freed.
+        self.callback = Some(callback);
+        Ok(())
+    }
+}
+
+/// Bundle a Rust closure with the C structure OpenVINO uses to call it.
+struct CompletionCallback {
+    ffi: ie_complete_call_back_t,
+    closure: Box<dyn FnMut() + Send>,
+}
+
+impl CompletionCallback {
+    unsafe extern "C" fn trampoline(args: *mut c_void) {
+        let callback = &mut *args.cast::<CompletionCallback>();
+        let _ = catch_unwind(AssertUnwindSafe(|| (callback.closure)()));
+    }
+}
+
+/// Describe the outcome of [`InferRequest::wait`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WaitStatus {
+    /// The inference has completed and its results are ready.
+    Ok,
+    /// The inference did not complete before the timeout elapsed.
+    Timeout,
+    /// The inference has not yet completed; only returned when polling.
+    Running,
 }
@@ -1,5 +1,6 @@
-use crate::{Layout, Precision};
+use crate::{BlobError, Layout, Precision};
 use openvino_sys::{dimensions_t, tensor_desc_t};
+use std::fmt;
 
 /// See
 /// [`TensorDesc`](https://docs.openvinotoolkit.org/latest/classInferenceEngine_1_1TensorDesc.html).
@@ -8,14 +9,20 @@ pub struct TensorDesc {
 }
 
 impl TensorDesc {
-    /// Construct a new [`TensorDesc`] from its C API components.
+    /// Construct a new [`TensorDesc`] from its C API components. An empty `dimensions` slice
+    /// describes a scalar (use [`Layout::SCALAR`]).
+    ///
+    /// All dimensions must be known: the OpenVINO C API has no representation for dynamic
+    /// dimensions. For models with, e.g., a variable sequence length, pin the network's input
+    /// shapes to concrete sizes with [`CNNNetwork::reshape`](crate::CNNNetwork::reshape) before
+    /// loading it, then describe and allocate blobs with those sizes.
     ///
     /// # Panics
     ///
     /// Only (currently) handles up to eight dimensions; will panic if exceeded.
     pub fn new(layout: Layout, dimensions: &[usize], precision: Precision) -> Self {
         // Setup dimensions.
-        assert!(dimensions.len() < 8);
+        assert!(dimensions.len() <= 8);
         let mut dims = [0; 8];
         dims[..dimensions.len()].copy_from_slice(dimensions);
 
@@ -32,6 +39,54 @@ impl TensorDesc {
         }
     }
 
+    /// Construct a new [`TensorDesc`] like [`TensorDesc::new`], but first check that the number of
+    /// `dimensions` matches the rank of `layout` (see [`Layout::rank`]), e.g., that an `NCHW`
+    /// descriptor has four dimensions; a mismatch returns [`BlobError::RankMismatch`]. Layouts
+    /// without a fixed rank (`ANY`, `BLOCKED`) accept any number of dimensions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more than eight dimensions; see [`TensorDesc::new`].
+    pub fn try_new(
+        layout: Layout,
+        dimensions: &[usize],
+        precision: Precision,
+    ) -> Result<Self, BlobError> {
+        match layout.rank() {
+            Some(rank) if rank != dimensions.len() => Err(BlobError::RankMismatch {
+                layout,
+                expected: rank,
+                actual: dimensions.len(),
+            }),
+            _ => Ok(Self::new(layout, dimensions, precision)),
+        }
+    }
+
+    /// Construct an `NCHW` [`TensorDesc`] for a batch of images, placing the dimensions in the
+    /// order of the layout, i.e., `[batch, channels, height, width]`.
+    pub fn image_nchw(
+        batch: usize,
+        channels: usize,
+        height: usize,
+        width: usize,
+        precision: Precision,
+    ) -> Self {
+        Self::new(Layout::NCHW, &[batch, channels, height, width], precision)
+    }
+
+    /// Construct an `NHWC` [`TensorDesc`] for a batch of images. Like OpenVINO, this lists the
+    /// dimensions in `NCHW` order (i.e., `[batch, channels, height, width]`) whatever the layout;
+    /// the layout only describes how the elements are arranged in memory.
+    pub fn image_nhwc(
+        batch: usize,
+        channels: usize,
+        height: usize,
+        width: usize,
+        precision: Precision,
+    ) -> Self {
+        Self::new(Layout::NHWC, &[batch, channels, height, width], precision)
+    }
+
     /// Layout of the tensor.
     pub fn layout(&self) -> Layout {
         self.instance.layout
@@ -49,8 +104,122 @@ impl TensorDesc {
         self.instance.precision
     }
 
-    /// Get the number of elements described by this [`TensorDesc`].
+    /// Get the number of elements described by this [`TensorDesc`], i.e., the product of its
+    /// dimensions. A scalar descriptor (with no dimensions) describes a single element.
     pub fn len(&self) -> usize {
         self.dims().iter().product()
     }
 }
+
+impl PartialEq for TensorDesc {
+    fn eq(&self, other: &Self) -> bool {
+        // Only the first `ranks` dimensions are meaningful.
+        self.layout() == other.layout()
+            && self.dims() == other.dims()
+            && self.precision() == other.precision()
+    }
+}
+
+impl Eq for TensorDesc {}
+
+impl fmt::Debug for TensorDesc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TensorDesc")
+            .field("layout", &self.layout())
+            .field("dims", &self.dims())
+            .field("precision", &self.precision())
+            .finish()
+    }
+}
+
+/// Format the descriptor as, e.g., `NCHW [1, 3, 224, 224] FP32`.
+impl fmt::Display for TensorDesc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {:?} {}",
+            self.layout(),
+            self.dims(),
+            self.precision()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn getters() {
+        let desc = TensorDesc::new(Layout::NCHW, &[1, 3, 227, 227], Precision::FP32);
+        assert_eq!(desc.layout(), Layout::NCHW);
+        assert_eq!(desc.dims(), &[1, 3, 227, 227]);
+        assert_eq!(desc.precision(), Precision::FP32);
+        assert_eq!(desc.len(), 3 * 227 * 227);
+    }
+
+    #[test]
+    fn equality_and_display() {
+        let desc = TensorDesc::new(Layout::NCHW, &[1, 3, 224, 224], Precision::FP32);
+        assert_eq!(
+            desc,
+            TensorDesc::new(Layout::NCHW, &[1, 3, 224, 224], Precision::FP32)
+        );
+        assert_ne!(
+            desc,
+            TensorDesc::new(Layout::NHWC, &[1, 3, 224, 224], Precision::FP32)
+        );
+        assert_ne!(
+            desc,
+            TensorDesc::new(Layout::NCHW, &[1, 3, 224], Precision::FP32)
+        );
+        assert_eq!(desc.to_string(), "NCHW [1, 3, 224, 224] FP32");
+    }
+
+    #[test]
+    fn eight_dimensions() {
+        let desc = TensorDesc::new(Layout::BLOCKED, &[1, 2, 1, 2, 1, 2, 1, 2], Precision::U8);
+        assert_eq!(desc.dims().len(), 8);
+        assert_eq!(desc.len(), 16);
+    }
+
+    #[test]
+    #[should_panic]
+    fn too_many_dimensions() {
+        let _ = TensorDesc::new(Layout::BLOCKED, &[1; 9], Precision::U8);
+    }
+
+    #[test]
+    fn scalar_len() {
+        let desc = TensorDesc::new(Layout::SCALAR, &[], Precision::U8);
+        assert!(desc.dims().is_empty());
+        assert_eq!(desc.len(), 1);
+    }
+
+    #[test]
+    fn check_rank() {
+        assert!(TensorDesc::try_new(Layout::NCHW, &[1, 3, 224, 224], Precision::FP32).is_ok());
+        assert!(TensorDesc::try_new(Layout::ANY, &[1, 3], Precision::FP32).is_ok());
+        assert_eq!(
+            TensorDesc::try_new(Layout::NCHW, &[3, 224, 224], Precision::FP32).err(),
+            Some(BlobError::RankMismatch {
+                layout: Layout::NCHW,
+                expected: 4,
+                actual: 3
+            })
+        );
+    }
+
+    #[test]
+    fn image_descriptions() {
+        let nchw = TensorDesc::image_nchw(1, 3, 224, 256, Precision::FP32);
+        assert_eq!(nchw.layout(), Layout::NCHW);
+        assert_eq!(nchw.dims(), &[1, 3, 224, 256]);
+        assert_eq!(nchw.precision(), Precision::FP32);
+
+        let nhwc = TensorDesc::image_nhwc(2, 3, 224, 256, Precision::U8);
+        assert_eq!(nhwc.layout(), Layout::NHWC);
+        assert_eq!(nhwc.dims(), &[2, 3, 224, 256]);
+        assert_eq!(nhwc.precision(), Precision::U8);
+    }
+}
@@ -1,5 +1,14 @@
 //! A collection of utility types and macros for use inside this crate.
-use crate::InferenceError;
+use crate::{keys, InferenceError};
+use openvino_sys::{ie_config_t, ie_param__bindgen_ty_1, ie_param_free, ie_param_t};
+use std::collections::hash_map::RandomState;
+use std::collections::HashMap;
+use std::ffi::{CStr, CString};
+use std::fs;
+use std::hash::{BuildHasher, Hasher};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
 
 /// This alias makes the implementation slightly less verbose.
 pub(crate) type Result<T> = std::result::Result<T, InferenceError>;
@@ -35,3 +44,299 @@ macro_rules! drop_using_function {
         }
     };
 }
+
+/// Convert a map of configuration keys and values into the linked list of `ie_config_t` entries
+/// expected by the C API. This structure owns the C strings pointed to by each entry, so it must be
+/// kept alive for the duration of any FFI call using [`Config::as_ptr`].
+pub(crate) struct Config {
+    _strings: Vec<(CString, CString)>,
+    entries: Vec<ie_config_t>,
+}
+
+impl Config {
+    /// Build the configuration list; an empty `map` produces a single, empty entry since the C API
+    /// does not accept a null pointer.
+    pub(crate) fn new(map: &HashMap<String, String>) -> Self {
+        let strings: Vec<(CString, CString)> = map
+            .iter()
+            .map(|(name, value)| {
+                (
+                    CString::new(name.as_str()).expect("a valid C string"),
+                    CString::new(value.as_str()).expect("a valid C string"),
+                )
+            })
+            .collect();
+
+        let mut entries: Vec<ie_config_t> = strings
+            .iter()
+            .map(|(name, value)| ie_config_t {
+                name: name.as_ptr(),
+                value: value.as_ptr(),
+                next: std::ptr::null_mut(),
+            })
+            .collect();
+        if entries.is_empty() {
+            entries.push(ie_config_t {
+                name: std::ptr::null(),
+                value: std::ptr::null(),
+                next: std::ptr::null_mut(),
+            });
+        }
+
+        // Link the entries together only once the vector will no longer be resized.
+        let base = entries.as_mut_ptr();
+        for i in 1..entries.len() {
+            entries[i - 1].next = unsafe { base.add(i) };
+        }
+
+        Self {
+            _strings: strings,
+            entries,
+        }
+    }
+
+    /// Retrieve a pointer to the head of the configuration list.
+    pub(crate) fn as_ptr(&self) -> *const ie_config_t {
+        self.entries.as_ptr()
+    }
+}
+
+/// Construct an empty `ie_param_t` for OpenVINO to populate.
+pub(crate) fn empty_param() -> ie_param_t {
+    ie_param_t {
+        __bindgen_anon_1: ie_param__bindgen_ty_1 {
+            params: std::ptr::null_mut(),
+        },
+    }
+}
+
+/// Copy a C string into an owned [`String`], treating a null pointer as an empty string.
+pub(crate) unsafe fn string_from_ptr(ptr: *const std::os::raw::c_char) -> String {
+    if ptr.is_null() {
+        String::new()
+    } else {
+        CStr::from_ptr(ptr).to_string_lossy().into_owned()
+    }
+}
+
+/// Copy the string contained in an `ie_param_t` returned by OpenVINO and free the C string.
+///
+/// # Safety
+///
+/// The caller must ensure that OpenVINO populated the `params` string field of `param`; other
+/// parameter kinds (e.g., numbers) must not be freed.
+pub(crate) unsafe fn take_string_param(param: &mut ie_param_t) -> String {
+    let rust_string = string_from_ptr(param.__bindgen_anon_1.params);
+    ie_param_free(param);
+    rust_string
+}
+
+/// Describe which field of the `ie_param_t` union OpenVINO populates for a metric.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum MetricKind {
+    /// A string, including lists joined by commas (e.g., `SUPPORTED_METRICS`).
+    String,
+    /// A [`keys::RANGE_FOR_ASYNC_INFER_REQUESTS`] triple.
+    AsyncRequestsRange,
+    /// A [`keys::RANGE_FOR_STREAMS`] pair.
+    StreamsRange,
+    /// An unsigned integer.
+    Number,
+}
+
+impl MetricKind {
+    /// Determine the kind of value OpenVINO returns for `metric`. Because the C API returns an
+    /// untagged union, only the metrics listed here can be read safely; any other metric returns
+    /// [`InferenceError::NotImplemented`] rather than misreading (and leaking) its value.
+    pub(crate) fn of(metric: &str) -> Result<Self> {
+        match metric {
+            keys::SUPPORTED_METRICS
+            | keys::SUPPORTED_CONFIG_KEYS
+            | keys::AVAILABLE_DEVICES
+            | keys::OPTIMIZATION_CAPABILITIES
+            | keys::FULL_DEVICE_NAME
+            | keys::DEVICE_ARCHITECTURE
+            | keys::NETWORK_NAME => Ok(Self::String),
+            keys::RANGE_FOR_ASYNC_INFER_REQUESTS => Ok(Self::AsyncRequestsRange),
+            keys::RANGE_FOR_STREAMS => Ok(Self::StreamsRange),
+            keys::OPTIMAL_NUMBER_OF_INFER_REQUESTS => Ok(Self::Number),
+            _ => Err(InferenceError::NotImplemented),
+        }
+    }
+}
+
+/// Stringify the metric value contained in an `ie_param_t` returned by OpenVINO, according to its
+/// `kind` (see [`MetricKind::of`]):
+/// - lists (e.g., `SUPPORTED_METRICS`) and names (e.g., `FULL_DEVICE_NAME`) are returned as-is
+/// - `RANGE_FOR_ASYNC_INFER_REQUESTS` is formatted as `"min..max step s"`
+/// - `RANGE_FOR_STREAMS` is formatted as `"min..max"`
+/// - numbers are formatted as decimal integers
+///
+/// # Safety
+///
+/// The caller must ensure that `param` was populated by OpenVINO for a metric of this `kind`.
+pub(crate) unsafe fn take_metric_param(kind: MetricKind, param: &mut ie_param_t) -> String {
+    match kind {
+        MetricKind::String => take_string_param(param),
+        // The other kinds own no memory; freeing them with `ie_param_free` would misread their
+        // value as a string pointer.
+        MetricKind::AsyncRequestsRange => {
+            let [min, max, step] = param.__bindgen_anon_1.range_for_async_infer_request;
+            format!("{min}..{max} step {step}")
+        }
+        MetricKind::StreamsRange => {
+            let [min, max] = param.__bindgen_anon_1.range_for_streams;
+            format!("{min}..{max}")
+        }
+        MetricKind::Number => param.__bindgen_anon_1.number.to_string(),
+    }
+}
+
+/// Own a private directory, created inside the system's temporary directory, for exchanging
+/// files with the C API, which only reads and writes some content (e.g., plugin configurations or
+/// exported networks) through files. The directory has a random name and, on Unix, is only
+/// accessible to the current user, so other users can neither guess nor tamper with the files in
+/// it; it is removed, along with its contents, when dropped. This still requires a writable
+/// temporary directory (see [`std::env::temp_dir`]).
+pub(crate) struct TemporaryDir {
+    path: PathBuf,
+}
+
+impl TemporaryDir {
+    /// Create a new, empty directory, retrying with another name if one already exists.
+    pub(crate) fn new() -> io::Result<Self> {
+        const ATTEMPTS: usize = 16;
+        static NEXT_DIR: AtomicUsize = AtomicUsize::new(0);
+        let mut builder = fs::DirBuilder::new();
+        #[cfg(unix)]
+        std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+        for _ in 0..ATTEMPTS {
+            // `RandomState` is seeded randomly for each instance, which is enough to make the
+            // name unpredictable without depending on a random number crate.
+            let suffix = RandomState::new().build_hasher().finish();
+            let path = std::env::temp_dir().join(format!(
+                "openvino-rs-{}-{}-{suffix:016x}",
+                std::process::id(),
+                NEXT_DIR.fetch_add(1, Ordering::Relaxed)
+            ));
+            match builder.create(&path) {
+                Ok(()) => return Ok(Self { path }),
+                // Another directory took this name; try another one.
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            "unable to create a unique temporary directory",
+        ))
+    }
+
+    /// Build the path of the file `name` inside this directory.
+    pub(crate) fn join(&self, name: &str) -> PathBuf {
+        self.path.join(name)
+    }
+
+    /// Create the file `name` inside this directory, which must not exist yet, and write
+    /// `content` to it; on Unix, the file is only accessible to the current user.
+    pub(crate) fn write(&self, name: &str, content: &[u8]) -> io::Result<PathBuf> {
+        let path = self.join(name);
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        options.open(&path)?.write_all(content)?;
+        Ok(path)
+    }
+}
+
+impl Drop for TemporaryDir {
+    fn drop(&mut self) {
+        // Failing to clean up a temporary directory is not worth panicking for.
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+
+    fn collect(config: &Config) -> HashMap<String, String> {
+        let mut found = HashMap::new();
+        let mut entry = config.as_ptr();
+        while !entry.is_null() && !unsafe { *entry }.name.is_null() {
+            let (name, value) = unsafe {
+                (
+                    CStr::from_ptr((*entry).name).to_string_lossy().into_owned(),
+                    CStr::from_ptr((*entry).value)
+                        .to_string_lossy()
+                        .into_owned(),
+                )
+            };
+            found.insert(name, value);
+            entry = unsafe { *entry }.next;
+        }
+        found
+    }
+
+    #[test]
+    fn empty_config() {
+        let config = Config::new(&HashMap::new());
+        assert!(!config.as_ptr().is_null());
+        assert!(collect(&config).is_empty());
+    }
+
+    #[test]
+    fn linked_config() {
+        let mut map = HashMap::new();
+        map.insert("PERF_COUNT".to_string(), "YES".to_string());
+        map.insert("CPU_THROUGHPUT_STREAMS".to_string(), "4".to_string());
+        map.insert("CPU_BIND_THREAD".to_string(), "NO".to_string());
+        let config = Config::new(&map);
+        assert_eq!(collect(&config), map);
+    }
+
+    #[test]
+    fn temporary_dir() {
+        let dir = TemporaryDir::new().unwrap();
+        let path = dir.write("config.xml", b"<ie/>").unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"<ie/>");
+        // Existing files are never overwritten.
+        assert_eq!(
+            dir.write("config.xml", b"").unwrap_err().kind(),
+            io::ErrorKind::AlreadyExists
+        );
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = |path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+            assert_eq!(mode(dir.path.as_path()), 0o700);
+            assert_eq!(mode(path.as_path()), 0o600);
+        }
+
+        let root = dir.path.clone();
+        drop(dir);
+        assert!(!root.exists());
+    }
+
+    #[test]
+    fn metric_kinds() {
+        assert_eq!(
+            MetricKind::of(keys::FULL_DEVICE_NAME).unwrap(),
+            MetricKind::String
+        );
+        assert_eq!(
+            MetricKind::of(keys::RANGE_FOR_STREAMS).unwrap(),
+            MetricKind::StreamsRange
+        );
+        assert_eq!(
+            MetricKind::of(keys::OPTIMAL_NUMBER_OF_INFER_REQUESTS).unwrap(),
+            MetricKind::Number
+        );
+        assert_eq!(
+            MetricKind::of("UNKNOWN_METRIC"),
+            Err(InferenceError::NotImplemented)
+        );
+    }
+}
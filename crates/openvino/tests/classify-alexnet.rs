@@ -5,12 +5,13 @@ mod util;
 
 use fixtures::alexnet::Fixture;
 use openvino::{Blob, Core, Layout, Precision, TensorDesc};
+use std::collections::HashMap;
 use std::fs;
 use util::{Prediction, Predictions};
 
 #[test]
 fn classify_alexnet() {
-    let mut core = Core::new(None).unwrap();
+    let core = Core::new(None).unwrap();
     let mut network = core
         .read_network_from_file(
             &Fixture::graph().to_string_lossy(),
@@ -25,7 +26,7 @@ fn classify_alexnet() {
     assert_eq!(output_name, "prob");
 
     // Load the network.
-    let mut executable_network = core.load_network(&network, "CPU").unwrap();
+    let executable_network = core.load_network(&network, "CPU", &HashMap::new()).unwrap();
     let mut infer_request = executable_network.create_infer_request().unwrap();
 
     // Read the image.
@@ -6,12 +6,13 @@ mod util;
 
 use fixtures::mobilenet::Fixture;
 use openvino::{Blob, Core, Layout, Precision, TensorDesc};
+use std::collections::HashMap;
 use std::fs;
 use util::{Prediction, Predictions};
 
 #[test]
 fn classify_mobilenet() {
-    let mut core = Core::new(None).unwrap();
+    let core = Core::new(None).unwrap();
     let mut network = core
         .read_network_from_file(
             &Fixture::graph().to_string_lossy(),
@@ -26,7 +27,7 @@ fn classify_mobilenet() {
     assert_eq!(output_name, "MobilenetV2/Predictions/Reshape_1");
 
     // Load the network.
-    let mut executable_network = core.load_network(&network, "CPU").unwrap();
+    let executable_network = core.load_network(&network, "CPU", &HashMap::new()).unwrap();
     let mut infer_request = executable_network.create_infer_request().unwrap();
 
     // Read the image.
@@ -4,11 +4,12 @@ mod fixtures;
 
 use fixtures::inception_ssd::Fixture;
 use openvino::{Blob, Core, Layout, Precision, ResizeAlgorithm, TensorDesc};
+use std::collections::HashMap;
 use std::fs;
 
 #[test]
 fn detect_inception() {
-    let mut core = Core::new(None).unwrap();
+    let core = Core::new(None).unwrap();
     let mut network = core
         .read_network_from_file(
             &Fixture::graph().to_string_lossy(),
@@ -34,7 +35,7 @@ fn detect_inception() {
         .unwrap();
 
     // Load the network.
-    let mut executable_network = core.load_network(&network, "CPU").unwrap();
+    let executable_network = core.load_network(&network, "CPU", &HashMap::new()).unwrap();
     let mut infer_request = executable_network.create_infer_request().unwrap();
 
     // Read the image.
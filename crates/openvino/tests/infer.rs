@@ -0,0 +1,330 @@
+//! These tests demonstrate the different ways of executing an [`InferRequest`].
+mod fixtures;
+
+use fixtures::alexnet::Fixture;
+use openvino::{
+    Blob, BlobError, Core, InferPool, InferRequest, InferenceError, Layout, Precision, TensorDesc,
+    WaitStatus,
+};
+use std::collections::HashMap;
+use std::fs;
+use std::sync::mpsc;
+
+/// Load the AlexNet fixture and prepare an [`InferRequest`] with its input tensor already set.
+fn prepare_request() -> (InferRequest, String) {
+    let core = Core::new(None).unwrap();
+    let mut network = core
+        .read_network_from_file(
+            &Fixture::graph().to_string_lossy(),
+            &Fixture::weights().to_string_lossy(),
+        )
+        .unwrap();
+    let input_name = network.get_input_name(0).unwrap();
+    network.set_input_layout(&input_name, Layout::NHWC).unwrap();
+    let output_name = network.get_output_name(0).unwrap();
+
+    let executable_network = core.load_network(&network, "CPU", &HashMap::new()).unwrap();
+    let mut infer_request = executable_network.create_infer_request().unwrap();
+
+    let tensor_data = fs::read(Fixture::tensor()).unwrap();
+    let tensor_desc = TensorDesc::new(Layout::NHWC, &[1, 3, 227, 227], Precision::FP32);
+    let blob = Blob::new(&tensor_desc, &tensor_data).unwrap();
+    infer_request.set_blob(&input_name, &blob).unwrap();
+    (infer_request, output_name)
+}
+
+#[test]
+fn infer_async() {
+    let (mut infer_request, output_name) = prepare_request();
+    infer_request.infer_async().unwrap();
+    assert_eq!(
+        infer_request.wait(InferRequest::WAIT_INFINITE),
+        Ok(WaitStatus::Ok)
+    );
+
+    // Once complete, polling should report the same status.
+    assert_eq!(
+        infer_request.wait(InferRequest::WAIT_POLL),
+        Ok(WaitStatus::Ok)
+    );
+    assert_eq!(infer_request.is_ready(), Ok(true));
+    let results = infer_request.get_blob(&output_name).unwrap();
+    assert_eq!(results.len().unwrap(), 1000);
+}
+
+#[test]
+fn infer_timeout() {
+    let (mut infer_request, output_name) = prepare_request();
+    infer_request.infer_timeout(10_000).unwrap();
+    let results = infer_request.get_blob(&output_name).unwrap();
+    assert_eq!(results.len().unwrap(), 1000);
+
+    // The request can be reused once the previous inference has completed.
+    infer_request.infer_timeout(10_000).unwrap();
+}
+
+#[test]
+fn set_blob_checked() {
+    let (mut infer_request, _) = prepare_request();
+    let expected = TensorDesc::new(Layout::NHWC, &[1, 3, 227, 227], Precision::FP32);
+    let blob = Blob::new(&expected, &fs::read(Fixture::tensor()).unwrap()).unwrap();
+    infer_request.set_blob_checked("data", &blob).unwrap();
+
+    // A blob with the wrong precision is rejected before reaching OpenVINO.
+    let actual = TensorDesc::new(Layout::NHWC, &[1, 3, 227, 227], Precision::U8);
+    let blob = Blob::allocate(&actual).unwrap();
+    assert_eq!(
+        infer_request.set_blob_checked("data", &blob),
+        Err(BlobError::DescMismatch {
+            expected: Box::new(expected),
+            actual: Box::new(actual)
+        })
+    );
+}
+
+#[test]
+fn dynamic_batching() {
+    let core = Core::new(None).unwrap();
+    let mut network = core
+        .read_network_from_file(
+            &Fixture::graph().to_string_lossy(),
+            &Fixture::weights().to_string_lossy(),
+        )
+        .unwrap();
+    network.set_batch_size(4).unwrap();
+
+    let executable_network = core.load_network_dyn_batch(&network, "CPU", 4).unwrap();
+    let mut infer_request = executable_network.create_infer_request().unwrap();
+    infer_request.set_batch_size(2).unwrap();
+    infer_request.infer().unwrap();
+    assert_eq!(
+        infer_request.set_batch_size(5),
+        Err(InferenceError::OutOfBounds)
+    );
+
+    // Without dynamic batching, the batch size of a request cannot be changed.
+    let (mut infer_request, _) = prepare_request();
+    assert_eq!(
+        infer_request.set_batch_size(1),
+        Err(InferenceError::NotImplemented)
+    );
+}
+
+#[test]
+fn blobs_by_index() {
+    let (mut infer_request, _) = prepare_request();
+    let tensor_desc = TensorDesc::new(Layout::NHWC, &[1, 3, 227, 227], Precision::FP32);
+    let blob = Blob::new(&tensor_desc, &fs::read(Fixture::tensor()).unwrap()).unwrap();
+    infer_request.set_input_blob(0, &blob).unwrap();
+    infer_request.infer().unwrap();
+    assert_eq!(infer_request.get_output_blob(0).unwrap().len(), Ok(1000));
+
+    assert_eq!(
+        infer_request.set_input_blob(1, &blob),
+        Err(InferenceError::OutOfBounds)
+    );
+    assert!(matches!(
+        infer_request.get_output_blob(1),
+        Err(InferenceError::OutOfBounds)
+    ));
+}
+
+#[test]
+fn reset_request() {
+    let (mut infer_request, output_name) = prepare_request();
+    let tensor_desc = TensorDesc::new(Layout::NHWC, &[1, 3, 227, 227], Precision::FP32);
+    let blob = Blob::new(&tensor_desc, &fs::read(Fixture::tensor()).unwrap()).unwrap();
+    infer_request.set_blob("data", &blob).unwrap();
+    let user_memory = blob.buffer().unwrap().as_ptr();
+
+    // After a reset, the request uses its own memory with the same description.
+    infer_request.reset().unwrap();
+    let input = infer_request.get_blob("data").unwrap();
+    assert_ne!(input.buffer().unwrap().as_ptr(), user_memory);
+    assert_eq!(input.tensor_desc().unwrap(), tensor_desc);
+    infer_request.infer().unwrap();
+    assert_eq!(
+        infer_request.get_blob(&output_name).unwrap().len(),
+        Ok(1000)
+    );
+}
+
+#[test]
+fn get_all_outputs() {
+    let (mut infer_request, output_name) = prepare_request();
+    infer_request.infer().unwrap();
+    let outputs = infer_request.get_all_outputs().unwrap();
+    assert_eq!(outputs.len(), 1);
+    assert_eq!(outputs[&output_name].len(), Ok(1000));
+}
+
+#[test]
+fn get_all_outputs_of_imported_network() {
+    let core = Core::new(None).unwrap();
+    let model = fs::read(Fixture::graph()).unwrap();
+    let weights = fs::read(Fixture::weights()).unwrap();
+    let network = core.read_network_from_buffer(&model, &weights).unwrap();
+    let executable_network = core.load_network(&network, "CPU", &HashMap::new()).unwrap();
+    let content = executable_network.export_to_vec().unwrap();
+    let imported = core.import_network_from_buffer(&content, "CPU").unwrap();
+
+    // Imported networks do not record their output names.
+    let infer_request = imported.create_infer_request().unwrap();
+    assert!(matches!(
+        infer_request.get_all_outputs(),
+        Err(InferenceError::NotImplemented)
+    ));
+}
+
+#[test]
+fn infer_with_callback() {
+    let (mut infer_request, _) = prepare_request();
+    let (sender, receiver) = mpsc::channel();
+    infer_request
+        .set_completion_callback(move || sender.send(()).unwrap())
+        .unwrap();
+    infer_request.infer_async().unwrap();
+    receiver.recv().unwrap();
+    assert_eq!(
+        infer_request.wait(InferRequest::WAIT_POLL),
+        Ok(WaitStatus::Ok)
+    );
+}
+
+#[test]
+fn replace_callback() {
+    let (mut infer_request, _) = prepare_request();
+    let (sender, receiver) = mpsc::channel();
+    for round in 0..3 {
+        let sender = sender.clone();
+        infer_request
+            .set_completion_callback(move || sender.send(round).unwrap())
+            .unwrap();
+        infer_request.infer_async().unwrap();
+        infer_request.wait(InferRequest::WAIT_INFINITE).unwrap();
+        // Only the most recently registered callback runs.
+        assert_eq!(receiver.recv(), Ok(round));
+    }
+    assert!(receiver.try_recv().is_err());
+}
+
+#[test]
+fn infer_pool() {
+    let core = Core::new(None).unwrap();
+    let mut network = core
+        .read_network_from_file(
+            &Fixture::graph().to_string_lossy(),
+            &Fixture::weights().to_string_lossy(),
+        )
+        .unwrap();
+    let input_name = network.get_input_name(0).unwrap();
+    network.set_input_layout(&input_name, Layout::NHWC).unwrap();
+    let output_name = network.get_output_name(0).unwrap();
+    let executable_network = core.load_network(&network, "CPU", &HashMap::new()).unwrap();
+    let pool = InferPool::new(executable_network, 2).unwrap();
+    assert_eq!(pool.size(), 2);
+
+    let tensor_data = fs::read(Fixture::tensor()).unwrap();
+    let tensor_desc = TensorDesc::new(Layout::NHWC, &[1, 3, 227, 227], Precision::FP32);
+    let pending: Vec<_> = (0..4)
+        .map(|_| {
+            let blob = Blob::new(&tensor_desc, &tensor_data).unwrap();
+            let inputs = HashMap::from([(input_name.clone(), blob)]);
+            pool.submit(inputs, &[&output_name])
+        })
+        .collect();
+    for result in pending {
+        let outputs = result.recv().unwrap().unwrap();
+        assert_eq!(outputs[&output_name].len().unwrap(), 1000);
+    }
+}
+
+#[test]
+fn empty_infer_pool() {
+    let core = Core::new(None).unwrap();
+    let model = fs::read(Fixture::graph()).unwrap();
+    let weights = fs::read(Fixture::weights()).unwrap();
+    let network = core.read_network_from_buffer(&model, &weights).unwrap();
+    let executable_network = core.load_network(&network, "CPU", &HashMap::new()).unwrap();
+    assert!(matches!(
+        InferPool::new(executable_network, 0),
+        Err(InferenceError::OutOfBounds)
+    ));
+}
+
+#[test]
+fn reuse_request() {
+    let (mut infer_request, output_name) = prepare_request();
+    let input_name = "data";
+    let mut input = infer_request.get_blob(input_name).unwrap();
+    let input_ptr = input.as_slice::<f32>().unwrap().as_ptr();
+    let output_ptr = infer_request
+        .get_blob(&output_name)
+        .unwrap()
+        .as_slice::<f32>()
+        .unwrap()
+        .as_ptr();
+
+    for i in 0..1000 {
+        // Overwrite the input in place; neither blob's memory should be reallocated.
+        input.as_mut_slice::<f32>().unwrap()[0] = i as f32;
+        infer_request.infer().unwrap();
+        let output = infer_request.get_blob(&output_name).unwrap();
+        assert_eq!(output.as_slice::<f32>().unwrap().as_ptr(), output_ptr);
+        let input = infer_request.get_blob(input_name).unwrap();
+        assert_eq!(input.as_slice::<f32>().unwrap().as_ptr(), input_ptr);
+    }
+}
+
+#[test]
+fn benchmark() {
+    let core = Core::new(None).unwrap();
+    let network = core
+        .read_network_from_file(
+            &Fixture::graph().to_string_lossy(),
+            &Fixture::weights().to_string_lossy(),
+        )
+        .unwrap();
+    let executable_network = core.load_network(&network, "CPU", &HashMap::new()).unwrap();
+
+    let result = executable_network.benchmark(8, 2).unwrap();
+    assert_eq!(result.iterations, 8);
+    assert_eq!(result.concurrent_requests, 2);
+    assert!(result.median_latency <= result.p99_latency);
+    assert!(result.fps > 0.0);
+
+    assert_eq!(
+        executable_network.benchmark(0, 2),
+        Err(InferenceError::OutOfBounds)
+    );
+}
+
+#[test]
+fn export_and_import_from_buffer() {
+    let core = Core::new(None).unwrap();
+    let mut network = core
+        .read_network_from_file(
+            &Fixture::graph().to_string_lossy(),
+            &Fixture::weights().to_string_lossy(),
+        )
+        .unwrap();
+    network.set_input_layout("data", Layout::NHWC).unwrap();
+    let executable_network = core.load_network(&network, "CPU", &HashMap::new()).unwrap();
+    let content = executable_network.export_to_vec().unwrap();
+    let imported = core.import_network_from_buffer(&content, "CPU").unwrap();
+
+    // Both networks compute the same results for the same input.
+    let tensor_desc = TensorDesc::new(Layout::NHWC, &[1, 3, 227, 227], Precision::FP32);
+    let blob = Blob::new(&tensor_desc, &fs::read(Fixture::tensor()).unwrap()).unwrap();
+    let results: Vec<Vec<f32>> = [executable_network, imported]
+        .iter()
+        .map(|network| {
+            let mut infer_request = network.create_infer_request().unwrap();
+            infer_request.set_blob("data", &blob).unwrap();
+            infer_request.infer().unwrap();
+            let output = infer_request.get_blob("prob").unwrap();
+            output.as_slice::<f32>().unwrap().to_vec()
+        })
+        .collect();
+    assert_eq!(results[0], results[1]);
+}
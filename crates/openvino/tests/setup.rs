@@ -2,12 +2,17 @@
 mod fixtures;
 
 use fixtures::alexnet::Fixture;
-use openvino::Core;
+use openvino::{
+    Blob, Core, InferenceError, Layout, LogLevel, PerformanceHint, Precision, ResizeAlgorithm,
+    SetupError, TensorDesc, ThreadBinding,
+};
+use std::collections::HashMap;
 use std::fs;
+use std::path::Path;
 
 #[test]
 fn read_network() {
-    let mut core = Core::new(None).unwrap();
+    let core = Core::new(None).unwrap();
     let model = fs::read(Fixture::graph()).unwrap();
     let weights = fs::read(Fixture::weights()).unwrap();
     let network = core.read_network_from_buffer(&model, &weights).unwrap();
@@ -15,4 +20,452 @@ fn read_network() {
     // Check the number of inputs and outputs.
     assert_eq!(network.get_inputs_len(), Ok(1));
     assert_eq!(network.get_outputs_len(), Ok(1));
+    assert_eq!(network.get_input_names(), Ok(vec!["data".to_string()]));
+    assert_eq!(network.get_output_names(), Ok(vec!["prob".to_string()]));
+}
+
+#[test]
+fn read_network_from_weights_blob() {
+    let core = Core::new(None).unwrap();
+    let model = fs::read(Fixture::graph()).unwrap();
+    let weights = fs::read(Fixture::weights()).unwrap();
+    let desc = TensorDesc::new(Layout::ANY, &[weights.len()], Precision::U8);
+    let weights = Blob::from_vec(&desc, weights).unwrap();
+    let network = core
+        .read_network_from_weights_blob(&model, &weights)
+        .unwrap();
+    assert_eq!(network.get_output_names(), Ok(vec!["prob".to_string()]));
+}
+
+#[test]
+fn list_available_devices() {
+    let core = Core::new(None).unwrap();
+    let devices = core.available_devices().unwrap();
+    assert!(devices.iter().all(|d| !d.is_empty()));
+}
+
+#[test]
+fn clone_core() {
+    let core = Core::new(None).unwrap();
+    let model = fs::read(Fixture::graph()).unwrap();
+    let weights = fs::read(Fixture::weights()).unwrap();
+
+    // Both clones share the same underlying core and remain usable after the other is dropped.
+    let clone = core.clone();
+    let handle = std::thread::spawn(move || {
+        let network = clone.read_network_from_buffer(&model, &weights).unwrap();
+        clone
+            .load_network(&network, "CPU", &HashMap::new())
+            .unwrap();
+    });
+    drop(core);
+    handle.join().unwrap();
+}
+
+#[test]
+fn core_from_plugin_config() {
+    let core = Core::from_plugin_config("<ie><plugins></plugins></ie>").unwrap();
+    core.available_devices().unwrap();
+}
+
+#[test]
+fn describe_devices() {
+    let core = Core::new(None).unwrap();
+    let devices = core.devices().unwrap();
+    let cpu = devices.iter().find(|device| device.name == "CPU").unwrap();
+    assert!(cpu.full_name.is_some());
+    assert!(cpu.precisions.iter().any(|precision| precision == "FP32"));
+}
+
+#[test]
+fn set_precision() {
+    let core = Core::new(None).unwrap();
+    let model = fs::read(Fixture::graph()).unwrap();
+    let weights = fs::read(Fixture::weights()).unwrap();
+    let mut network = core.read_network_from_buffer(&model, &weights).unwrap();
+    let input_name = network.get_input_name(0).unwrap();
+    let output_name = network.get_output_name(0).unwrap();
+
+    network
+        .set_input_precision(&input_name, Precision::U8)
+        .unwrap();
+    assert_eq!(network.get_input_precision(&input_name), Ok(Precision::U8));
+    network
+        .set_output_precision(&output_name, Precision::FP16)
+        .unwrap();
+    assert_eq!(
+        network.get_output_precision(&output_name),
+        Ok(Precision::FP16)
+    );
+
+    // Unknown names are reported as errors.
+    assert!(network.get_input_precision("unknown").is_err());
+    assert!(network
+        .set_input_precision("unknown", Precision::U8)
+        .is_err());
+}
+
+#[test]
+fn reshape() {
+    let core = Core::new(None).unwrap();
+    let model = fs::read(Fixture::graph()).unwrap();
+    let weights = fs::read(Fixture::weights()).unwrap();
+    let mut network = core.read_network_from_buffer(&model, &weights).unwrap();
+
+    let mut shapes = HashMap::new();
+    shapes.insert("data".to_string(), vec![2, 3, 227, 227]);
+    network.reshape(&shapes).unwrap();
+    assert_eq!(network.get_batch_size(), Ok(2));
+
+    // A new shape must keep the same rank.
+    shapes.insert("data".to_string(), vec![2, 3, 227]);
+    assert_eq!(
+        network.reshape(&shapes),
+        Err(InferenceError::ParameterMismatch)
+    );
+}
+
+#[test]
+fn get_dims() {
+    let core = Core::new(None).unwrap();
+    let model = fs::read(Fixture::graph()).unwrap();
+    let weights = fs::read(Fixture::weights()).unwrap();
+    let network = core.read_network_from_buffer(&model, &weights).unwrap();
+    assert!(!network.name().unwrap().is_empty());
+    assert_eq!(
+        network.serialize("network.xml", "network.bin"),
+        Err(InferenceError::NotImplemented)
+    );
+    assert_eq!(network.get_input_dims("data"), Ok(vec![1, 3, 227, 227]));
+    assert_eq!(network.get_output_dims("prob"), Ok(vec![1, 1000]));
+    assert_eq!(
+        network.get_input_dims("missing"),
+        Err(InferenceError::NotFound)
+    );
+}
+
+#[test]
+fn read_onnx_from_buffer() {
+    // A hand-encoded ONNX model with a single `Identity` node from input `x` to output `y`, both
+    // `f32` tensors of shape `[4]`.
+    const IDENTITY_ONNX: &[u8] = &[
+        8, 7, 58, 62, 10, 16, 10, 1, 120, 18, 1, 121, 34, 8, 73, 100, 101, 110, 116, 105, 116, 121,
+        18, 8, 105, 100, 101, 110, 116, 105, 116, 121, 90, 15, 10, 1, 120, 18, 10, 10, 8, 8, 1, 18,
+        4, 10, 2, 8, 4, 98, 15, 10, 1, 121, 18, 10, 10, 8, 8, 1, 18, 4, 10, 2, 8, 4, 66, 2, 16, 13,
+    ];
+    let core = Core::new(None).unwrap();
+    let network = core.read_onnx_from_buffer(IDENTITY_ONNX).unwrap();
+    assert_eq!(network.get_input_names(), Ok(vec!["x".to_string()]));
+    assert_eq!(network.get_input_dims("x"), Ok(vec![4]));
+}
+
+#[test]
+fn set_all_inputs() {
+    // A hand-encoded ONNX model adding inputs `a` and `b` into output `c`, all `f32` tensors of
+    // shape `[1, 4]`.
+    const ADD_ONNX: &[u8] = &[
+        8, 7, 58, 84, 10, 14, 10, 1, 97, 10, 1, 98, 18, 1, 99, 34, 3, 65, 100, 100, 18, 3, 97, 100,
+        100, 90, 19, 10, 1, 97, 18, 14, 10, 12, 8, 1, 18, 8, 10, 2, 8, 1, 10, 2, 8, 4, 90, 19, 10,
+        1, 98, 18, 14, 10, 12, 8, 1, 18, 8, 10, 2, 8, 1, 10, 2, 8, 4, 98, 19, 10, 1, 99, 18, 14,
+        10, 12, 8, 1, 18, 8, 10, 2, 8, 1, 10, 2, 8, 4, 66, 2, 16, 13,
+    ];
+    let core = Core::new(None).unwrap();
+    let mut network = core.read_onnx_from_buffer(ADD_ONNX).unwrap();
+    assert_eq!(network.get_inputs_len(), Ok(2));
+
+    network.set_all_input_precisions(Precision::U8).unwrap();
+    network.set_all_input_layouts(Layout::CN).unwrap();
+    for name in ["a", "b"] {
+        assert_eq!(network.get_input_precision(name), Ok(Precision::U8));
+        assert_eq!(network.get_input_layout(name), Ok(Layout::CN));
+    }
+}
+
+#[test]
+fn read_missing_onnx() {
+    let core = Core::new(None).unwrap();
+    match core.read_onnx_from_file("/path/to/missing.onnx") {
+        Err(SetupError::MissingModel(path)) => {
+            assert_eq!(path, Path::new("/path/to/missing.onnx"));
+        }
+        _ => panic!("expected the missing model to be reported"),
+    }
+}
+
+#[test]
+fn read_network_auto_weights() {
+    let core = Core::new(None).unwrap();
+    let network = core
+        .read_network_auto_weights(&Fixture::graph().to_string_lossy())
+        .unwrap();
+    assert_eq!(network.get_input_names(), Ok(vec!["data".to_string()]));
+
+    match core.read_network_auto_weights("/path/to/missing.xml") {
+        Err(SetupError::MissingWeights(path)) => {
+            assert_eq!(path, Path::new("/path/to/missing.bin"));
+        }
+        _ => panic!("expected the missing weights to be reported"),
+    }
+}
+
+#[test]
+fn export_and_import() {
+    let core = Core::new(None).unwrap();
+    let model = fs::read(Fixture::graph()).unwrap();
+    let weights = fs::read(Fixture::weights()).unwrap();
+    let network = core.read_network_from_buffer(&model, &weights).unwrap();
+    let executable_network = core.load_network(&network, "CPU", &HashMap::new()).unwrap();
+
+    let path = std::env::temp_dir().join("openvino-rs-exported.blob");
+    let path = path.to_string_lossy();
+    executable_network.export(&path).unwrap();
+    let imported = core.import_network(&path, "CPU").unwrap();
+    imported.create_infer_request().unwrap();
+    fs::remove_file(&*path).unwrap();
+}
+
+#[test]
+fn set_and_get_config() {
+    let core = Core::new(None).unwrap();
+    let mut config = HashMap::new();
+    config.insert("PERF_COUNT".to_string(), "YES".to_string());
+    core.set_config(&config, "CPU").unwrap();
+    assert_eq!(core.get_config("CPU", "PERF_COUNT"), Ok("YES".to_string()));
+}
+
+#[test]
+fn shared_config() {
+    // Configuration changes made through one clone are visible through all of them.
+    let core = Core::new(None).unwrap();
+    let clone = core.clone();
+    clone.set_num_threads(3).unwrap();
+    assert_eq!(
+        core.get_config("CPU", "CPU_THREADS_NUM"),
+        Ok("3".to_string())
+    );
+}
+
+#[test]
+fn set_cpu_threads() {
+    let core = Core::new(None).unwrap();
+    core.set_num_threads(2).unwrap();
+    assert_eq!(
+        core.get_config("CPU", "CPU_THREADS_NUM"),
+        Ok("2".to_string())
+    );
+    core.set_thread_binding(ThreadBinding::Numa).unwrap();
+    assert_eq!(
+        core.get_config("CPU", "CPU_BIND_THREAD"),
+        Ok("NUMA".to_string())
+    );
+}
+
+#[test]
+fn set_log_level() {
+    let core = Core::new(None).unwrap();
+    core.set_log_level(LogLevel::Warning, "CPU").unwrap();
+    assert_eq!(
+        core.get_config("CPU", "LOG_LEVEL"),
+        Ok("LOG_WARNING".to_string())
+    );
+}
+
+#[test]
+fn set_performance_hint() {
+    let core = Core::new(None).unwrap();
+    core.set_performance_hint(PerformanceHint::Throughput, "CPU")
+        .unwrap();
+    core.set_performance_hint_num_requests(4, "CPU").unwrap();
+    assert_eq!(
+        core.get_config("CPU", "PERFORMANCE_HINT"),
+        Ok("THROUGHPUT".to_string())
+    );
+}
+
+#[test]
+fn load_network_auto() {
+    let core = Core::new(None).unwrap();
+    let model = fs::read(Fixture::graph()).unwrap();
+    let weights = fs::read(Fixture::weights()).unwrap();
+    let network = core.read_network_from_buffer(&model, &weights).unwrap();
+    let executable_network = core
+        .load_network_auto(&network, &["CPU"], PerformanceHint::Latency)
+        .unwrap();
+    executable_network.create_infer_request().unwrap();
+}
+
+#[test]
+fn get_metrics() {
+    let core = Core::new(None).unwrap();
+    let name = core.get_metric("CPU", "FULL_DEVICE_NAME").unwrap();
+    assert!(!name.is_empty());
+    let range = core
+        .get_metric("CPU", "RANGE_FOR_ASYNC_INFER_REQUESTS")
+        .unwrap();
+    assert!(range.contains("..") && range.contains(" step "));
+}
+
+#[test]
+fn get_versions() {
+    let core = Core::new(None).unwrap();
+    let versions = core.get_versions("CPU").unwrap();
+    assert!(versions.contains_key("CPU"));
+}
+
+#[test]
+fn set_batch_size() {
+    let core = Core::new(None).unwrap();
+    let model = fs::read(Fixture::graph()).unwrap();
+    let weights = fs::read(Fixture::weights()).unwrap();
+    let mut network = core.read_network_from_buffer(&model, &weights).unwrap();
+
+    assert_eq!(network.get_batch_size(), Ok(1));
+    network.set_batch_size(8).unwrap();
+    assert_eq!(network.get_batch_size(), Ok(8));
+}
+
+#[test]
+fn set_resize_algorithm() {
+    let core = Core::new(None).unwrap();
+    let model = fs::read(Fixture::graph()).unwrap();
+    let weights = fs::read(Fixture::weights()).unwrap();
+    let mut network = core.read_network_from_buffer(&model, &weights).unwrap();
+    let input_name = network.get_input_name(0).unwrap();
+
+    assert_eq!(
+        network.get_input_resize_algorithm(&input_name),
+        Ok(ResizeAlgorithm::NO_RESIZE)
+    );
+    network
+        .set_input_resize_algorithm(&input_name, ResizeAlgorithm::RESIZE_AREA)
+        .unwrap();
+    assert_eq!(
+        network.get_input_resize_algorithm(&input_name),
+        Ok(ResizeAlgorithm::RESIZE_AREA)
+    );
+    assert!(network
+        .set_input_resize_algorithm("unknown", ResizeAlgorithm::RESIZE_AREA)
+        .is_err());
+}
+
+#[test]
+fn set_layout() {
+    let core = Core::new(None).unwrap();
+    let model = fs::read(Fixture::graph()).unwrap();
+    let weights = fs::read(Fixture::weights()).unwrap();
+    let mut network = core.read_network_from_buffer(&model, &weights).unwrap();
+    let input_name = network.get_input_name(0).unwrap();
+    let output_name = network.get_output_name(0).unwrap();
+
+    network.set_input_layout(&input_name, Layout::NHWC).unwrap();
+    assert_eq!(network.get_input_layout(&input_name), Ok(Layout::NHWC));
+    network.set_output_layout(&output_name, Layout::NC).unwrap();
+    assert_eq!(network.get_output_layout(&output_name), Ok(Layout::NC));
+}
+
+#[test]
+fn compile_model_from_file() {
+    let core = Core::new(None).unwrap();
+    let executable_network = core
+        .compile_model_from_file(
+            &Fixture::graph().to_string_lossy(),
+            &Fixture::weights().to_string_lossy(),
+            "CPU",
+            &HashMap::new(),
+        )
+        .unwrap();
+    executable_network.create_infer_request().unwrap();
+    // OpenVINO loads the network directly, so its names are not recorded.
+    assert_eq!(
+        executable_network.get_input_names(),
+        Err(InferenceError::NotImplemented)
+    );
+}
+
+#[test]
+fn set_cache_dir() {
+    let cache_dir = std::env::temp_dir().join("openvino-rs-cache");
+    let core = Core::new(None).unwrap();
+    core.set_cache_dir(&cache_dir.to_string_lossy()).unwrap();
+    let network = core
+        .read_network_from_file(
+            &Fixture::graph().to_string_lossy(),
+            &Fixture::weights().to_string_lossy(),
+        )
+        .unwrap();
+    core.load_network(&network, "CPU", &HashMap::new()).unwrap();
+    assert!(cache_dir.is_dir());
+    fs::remove_dir_all(cache_dir).unwrap();
+}
+
+#[test]
+fn add_missing_extension() {
+    let core = Core::new(None).unwrap();
+    assert!(core.add_extension("not-a-real-extension.so").is_err());
+}
+
+#[test]
+fn executable_network_info() {
+    let core = Core::new(None).unwrap();
+    let model = fs::read(Fixture::graph()).unwrap();
+    let weights = fs::read(Fixture::weights()).unwrap();
+    let mut network = core.read_network_from_buffer(&model, &weights).unwrap();
+    network.set_batch_size(2).unwrap();
+    let executable_network = core.load_network(&network, "CPU", &HashMap::new()).unwrap();
+
+    let input = executable_network.get_input_info("data").unwrap();
+    assert_eq!(input.dims(), &[2, 3, 227, 227]);
+    let output = executable_network.get_output_info("prob").unwrap();
+    assert_eq!(output.dims(), &[2, 1000]);
+    assert!(matches!(
+        executable_network.get_input_info("missing"),
+        Err(InferenceError::NotFound)
+    ));
+}
+
+#[test]
+fn executable_network_names() {
+    let core = Core::new(None).unwrap();
+    let model = fs::read(Fixture::graph()).unwrap();
+    let weights = fs::read(Fixture::weights()).unwrap();
+    let network = core.read_network_from_buffer(&model, &weights).unwrap();
+    let executable_network = core.load_network(&network, "CPU", &HashMap::new()).unwrap();
+    assert_eq!(executable_network.get_input_names().unwrap(), ["data"]);
+    assert_eq!(executable_network.get_output_names().unwrap(), ["prob"]);
+
+    // Imported networks do not record their names.
+    let content = executable_network.export_to_vec().unwrap();
+    let imported = core.import_network_from_buffer(&content, "CPU").unwrap();
+    assert_eq!(
+        imported.get_input_names(),
+        Err(InferenceError::NotImplemented)
+    );
+    assert_eq!(
+        imported.get_output_names(),
+        Err(InferenceError::NotImplemented)
+    );
+}
+
+#[test]
+fn executable_network_metrics() {
+    let core = Core::new(None).unwrap();
+    let model = fs::read(Fixture::graph()).unwrap();
+    let weights = fs::read(Fixture::weights()).unwrap();
+    let network = core.read_network_from_buffer(&model, &weights).unwrap();
+    let executable_network = core.load_network(&network, "CPU", &HashMap::new()).unwrap();
+
+    let requests = executable_network
+        .get_metric("OPTIMAL_NUMBER_OF_INFER_REQUESTS")
+        .unwrap();
+    assert!(requests.parse::<u32>().unwrap() > 0);
+    assert_eq!(
+        executable_network.optimal_number_of_infer_requests(),
+        Ok(requests.parse().unwrap())
+    );
+    assert!(executable_network.number_of_streams().unwrap() > 0);
+    assert!(!executable_network
+        .get_metric("NETWORK_NAME")
+        .unwrap()
+        .is_empty());
+    assert!(executable_network.get_config("CPU_THREADS_NUM").is_ok());
 }